@@ -0,0 +1,17 @@
+use business::calendar;
+use chrono::naive::NaiveDate;
+
+fn main() {
+    // A four-day week with the UK Christmas bank holidays.
+    let cal = calendar!(
+        working_days: [Monday, Tuesday, Wednesday, Thursday],
+        holidays: [2017-12-25, 2017-12-26],
+    );
+
+    let xmas_eve = NaiveDate::from_ymd_opt(2017, 12, 24).unwrap();
+
+    println!(
+        "The first business day after Christmas Eve is: {}",
+        cal.roll_forward(xmas_eve),
+    );
+}
@@ -0,0 +1,148 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use chrono::{naive::NaiveDate, Datelike, Duration};
+
+use crate::Calendar;
+
+/// A set of period boundaries that a date falls on, as returned by
+/// [`Calendar::business_day_boundary_check`]. Boundaries are combined with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BoundaryType(u8);
+
+impl BoundaryType {
+    /// The first calendar day of a month
+    pub const FIRST_OF_MONTH: Self = Self(1);
+    /// The last calendar day of a month
+    pub const LAST_OF_MONTH: Self = Self(1 << 1);
+    /// The first business day of a month
+    pub const FIRST_BUSINESS_OF_MONTH: Self = Self(1 << 2);
+    /// The last business day of a month
+    pub const LAST_BUSINESS_OF_MONTH: Self = Self(1 << 3);
+    /// The first calendar day of a quarter
+    pub const FIRST_OF_QUARTER: Self = Self(1 << 4);
+    /// The last calendar day of a quarter
+    pub const LAST_OF_QUARTER: Self = Self(1 << 5);
+    /// The first calendar day of a year
+    pub const FIRST_OF_YEAR: Self = Self(1 << 6);
+    /// The last calendar day of a year
+    pub const LAST_OF_YEAR: Self = Self(1 << 7);
+
+    /// Returns the set without any boundaries.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns `true` if there are no boundaries in the set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every boundary in `other` is also in this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for BoundaryType {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for BoundaryType {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Calendar {
+    /// Returns every month, quarter and year boundary that the date falls on. Business day
+    /// boundaries are only set for business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{BoundaryType, Calendar};
+    /// let cal = Calendar::workweek();
+    /// // Friday
+    /// let end_of_q3 = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// assert_eq!(
+    ///     cal.business_day_boundary_check(end_of_q3),
+    ///     BoundaryType::LAST_OF_MONTH
+    ///         | BoundaryType::LAST_BUSINESS_OF_MONTH
+    ///         | BoundaryType::LAST_OF_QUARTER
+    /// );
+    ///
+    /// let mid_month = NaiveDate::from_ymd_opt(2022, 9, 15).unwrap();
+    /// assert!(cal.business_day_boundary_check(mid_month).is_empty());
+    /// ```
+    pub fn business_day_boundary_check(&self, date: NaiveDate) -> BoundaryType {
+        let mut boundary = BoundaryType::empty();
+        let is_first = date.day() == 1;
+        let is_last = (date + Duration::days(1)).day() == 1;
+
+        if is_first {
+            boundary |= BoundaryType::FIRST_OF_MONTH;
+            if date.month() % 3 == 1 {
+                boundary |= BoundaryType::FIRST_OF_QUARTER;
+            }
+            if date.month() == 1 {
+                boundary |= BoundaryType::FIRST_OF_YEAR;
+            }
+        }
+        if is_last {
+            boundary |= BoundaryType::LAST_OF_MONTH;
+            if date.month() % 3 == 0 {
+                boundary |= BoundaryType::LAST_OF_QUARTER;
+            }
+            if date.month() == 12 {
+                boundary |= BoundaryType::LAST_OF_YEAR;
+            }
+        }
+        if self.business_day_of_month_index(date) == Some(1) {
+            boundary |= BoundaryType::FIRST_BUSINESS_OF_MONTH;
+        }
+        if self.business_day_before_month_end(date) == Some(0) {
+            boundary |= BoundaryType::LAST_BUSINESS_OF_MONTH;
+        }
+
+        boundary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_business_day_after_new_year_holiday() {
+        let new_year = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+        let cal = Calendar::with_holidays(&[new_year]);
+
+        assert_eq!(
+            cal.business_day_boundary_check(new_year),
+            BoundaryType::FIRST_OF_MONTH
+                | BoundaryType::FIRST_OF_QUARTER
+                | BoundaryType::FIRST_OF_YEAR
+        );
+        assert_eq!(
+            cal.business_day_boundary_check(mon),
+            BoundaryType::FIRST_BUSINESS_OF_MONTH
+        );
+    }
+
+    #[test]
+    fn last_of_year_on_business_day() {
+        let cal = Calendar::workweek();
+        let boundary =
+            cal.business_day_boundary_check(NaiveDate::from_ymd_opt(2021, 12, 31).unwrap());
+
+        assert!(boundary.contains(BoundaryType::LAST_OF_YEAR | BoundaryType::LAST_OF_QUARTER));
+        assert!(boundary.contains(BoundaryType::LAST_BUSINESS_OF_MONTH));
+        assert!(!boundary.contains(BoundaryType::FIRST_OF_MONTH));
+    }
+}
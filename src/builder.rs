@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{naive::NaiveDate, Weekday};
+
+use crate::{workweek, Calendar, CalendarError};
+
+/// Builds a [`Calendar`] step by step, validating it once at the end.
+///
+/// # Examples
+///
+/// ```
+/// # use chrono::{NaiveDate, Weekday};
+/// # use business::CalendarBuilder;
+/// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+///
+/// let cal = CalendarBuilder::new()
+///     .working_days(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu])
+///     .add_holiday(xmas)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(cal.is_business_day(xmas), false);
+/// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2020, 12, 24).unwrap()), true);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarBuilder {
+    working_days: HashSet<Weekday>,
+    holidays: HashSet<NaiveDate>,
+    holiday_names: HashMap<NaiveDate, Vec<String>>,
+    min_working_days: Option<u32>,
+}
+
+impl CalendarBuilder {
+    /// Creates a builder with Mon-Fri as working days and no holidays.
+    pub fn new() -> Self {
+        Self {
+            working_days: workweek(),
+            holidays: HashSet::new(),
+            holiday_names: HashMap::new(),
+            min_working_days: None,
+        }
+    }
+
+    /// Replaces the working days of the week.
+    pub fn working_days(mut self, working_days: &[Weekday]) -> Self {
+        self.working_days = working_days.iter().cloned().collect();
+        self
+    }
+
+    /// Adds a holiday.
+    pub fn add_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Adds several holidays.
+    pub fn add_holidays(mut self, dates: &[NaiveDate]) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Requires at least `min_per_week` working days when building the calendar.
+    pub fn with_minimum_working_days_check(mut self, min_per_week: u32) -> Self {
+        self.min_working_days = Some(min_per_week);
+        self
+    }
+
+    /// Builds the calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::NoWorkingDays`] if no working days are set, or
+    /// [`CalendarError::TooFewWorkingDays`] if there are fewer than the minimum set with
+    /// [`with_minimum_working_days_check`](Self::with_minimum_working_days_check).
+    pub fn build(self) -> Result<Calendar, CalendarError> {
+        if self.working_days.is_empty() {
+            return Err(CalendarError::NoWorkingDays);
+        }
+        if let Some(minimum) = self.min_working_days {
+            let found = self.working_days.len();
+            if found < minimum as usize {
+                return Err(CalendarError::TooFewWorkingDays { found, minimum });
+            }
+        }
+
+        let mut holiday_names = self.holiday_names;
+        holiday_names.retain(|date, _| self.holidays.contains(date));
+        Ok(Calendar {
+            working_days: self.working_days,
+            holidays: self.holidays,
+            holiday_names,
+        })
+    }
+}
+
+impl Default for CalendarBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Starts a builder with the same working days, holidays and holiday names as the calendar.
+impl From<&Calendar> for CalendarBuilder {
+    fn from(cal: &Calendar) -> Self {
+        Self {
+            working_days: cal.working_days.clone(),
+            holidays: cal.holidays.clone(),
+            holiday_names: cal.holiday_names.clone(),
+            min_working_days: None,
+        }
+    }
+}
+
+impl Calendar {
+    /// Creates a [`CalendarBuilder`] with Mon-Fri as working days and no holidays.
+    pub fn builder() -> CalendarBuilder {
+        CalendarBuilder::new()
+    }
+
+    /// Returns a modified copy of the calendar, by applying `f` to a [`CalendarBuilder`] started
+    /// from this calendar and building the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns any validation error from [`CalendarBuilder::build`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    ///
+    /// let new_cal = cal.modify_with(|b| b.add_holiday(xmas)).unwrap();
+    ///
+    /// assert_eq!(cal.is_business_day(xmas), true);
+    /// assert_eq!(new_cal.is_business_day(xmas), false);
+    /// ```
+    pub fn modify_with<F>(&self, f: F) -> Result<Calendar, CalendarError>
+    where
+        F: FnOnce(CalendarBuilder) -> CalendarBuilder,
+    {
+        f(CalendarBuilder::from(self)).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_defaults_to_workweek() {
+        assert_eq!(Calendar::builder().build(), Ok(Calendar::workweek()));
+    }
+
+    #[test]
+    fn build_without_working_days_is_error() {
+        let result = Calendar::builder().working_days(&[]).build();
+
+        assert_eq!(result, Err(CalendarError::NoWorkingDays));
+    }
+
+    #[test]
+    fn modify_with_keeps_existing_calendar() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 26).unwrap();
+        let mut cal = Calendar::with_holidays(&[xmas]);
+        cal.add_holiday_name(xmas, "Christmas Day");
+
+        let new_cal = cal
+            .modify_with(|b| b.working_days(&[Weekday::Sat]).add_holiday(boxing_day))
+            .unwrap();
+
+        assert_eq!(new_cal.working_days, [Weekday::Sat].into_iter().collect());
+        assert_eq!(new_cal.holidays, [xmas, boxing_day].into_iter().collect());
+        assert_eq!(new_cal.holiday_names, cal.holiday_names);
+    }
+
+    #[test]
+    fn modify_with_propagates_build_error() {
+        let cal = Calendar::workweek();
+
+        assert_eq!(
+            cal.modify_with(|b| b.working_days(&[])),
+            Err(CalendarError::NoWorkingDays)
+        );
+    }
+
+    #[test]
+    fn build_checks_minimum_working_days() {
+        let builder = Calendar::builder()
+            .working_days(&[Weekday::Mon, Weekday::Tue])
+            .with_minimum_working_days_check(3);
+
+        assert_eq!(
+            builder.clone().build(),
+            Err(CalendarError::TooFewWorkingDays {
+                found: 2,
+                minimum: 3
+            })
+        );
+        assert!(builder
+            .working_days(&[Weekday::Mon, Weekday::Tue, Weekday::Wed])
+            .build()
+            .is_ok());
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::RangeInclusive;
+
+use chrono::{naive::NaiveDate, Datelike, Duration, Weekday};
+
+use crate::{first_day_of_month, last_day_of_month, Calendar};
+
+/// A rule for operational bank closures, such as system maintenance, that are not ordinary
+/// holidays. Used with [`Calendar::with_bank_closure_rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BankClosureRule {
+    /// Closed on the last Friday of December every year
+    LastFridayOfDecember,
+    /// Closed on the three days immediately before the date
+    ThreeConsecutiveDaysBefore(NaiveDate),
+    /// Closed on every day from the first date to the second, inclusive
+    RangeExclusion(NaiveDate, NaiveDate),
+}
+
+impl BankClosureRule {
+    /// Returns the closure dates of the rule that fall in the given years, in chronological
+    /// order.
+    pub fn dates(&self, years: RangeInclusive<i32>) -> Vec<NaiveDate> {
+        let dates: Vec<_> = match *self {
+            BankClosureRule::LastFridayOfDecember => years
+                .clone()
+                .map(|year| {
+                    let last = last_day_of_month(year, 12);
+                    let days_back = (7 + last.weekday().num_days_from_monday()
+                        - Weekday::Fri.num_days_from_monday())
+                        % 7;
+                    last - Duration::days(i64::from(days_back))
+                })
+                .collect(),
+            BankClosureRule::ThreeConsecutiveDaysBefore(date) => (1..=3)
+                .rev()
+                .map(|days| date - Duration::days(days))
+                .collect(),
+            BankClosureRule::RangeExclusion(start, end) => {
+                let start = start.max(first_day_of_month(*years.start(), 1));
+                let end = end.min(last_day_of_month(*years.end(), 12));
+                start.iter_days().take_while(|date| *date <= end).collect()
+            }
+        };
+        dates
+            .into_iter()
+            .filter(|date| years.contains(&date.year()))
+            .collect()
+    }
+}
+
+impl Calendar {
+    /// Adds the closure dates of every rule that fall in the given years to the calendar's
+    /// holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{BankClosureRule, Calendar};
+    /// let maintenance = BankClosureRule::RangeExclusion(
+    ///     NaiveDate::from_ymd_opt(2022, 8, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2022, 8, 2).unwrap(),
+    /// );
+    /// let cal = Calendar::workweek().with_bank_closure_rules(
+    ///     vec![BankClosureRule::LastFridayOfDecember, maintenance],
+    ///     2022..=2023,
+    /// );
+    ///
+    /// assert_eq!(cal.holidays.len(), 4);
+    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2023, 12, 29).unwrap()), false);
+    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2022, 8, 2).unwrap()), false);
+    /// ```
+    pub fn with_bank_closure_rules(
+        mut self,
+        closures: Vec<BankClosureRule>,
+        years: RangeInclusive<i32>,
+    ) -> Calendar {
+        for closure in closures {
+            self.holidays.extend(closure.dates(years.clone()));
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn last_friday_of_december_when_year_ends_on_friday() {
+        assert_eq!(
+            BankClosureRule::LastFridayOfDecember.dates(2021..=2023),
+            vec![ymd(2021, 12, 31), ymd(2022, 12, 30), ymd(2023, 12, 29)]
+        );
+    }
+
+    #[test]
+    fn fixed_date_rules_are_limited_to_years() {
+        assert_eq!(
+            BankClosureRule::ThreeConsecutiveDaysBefore(ymd(2023, 1, 2)).dates(2022..=2022),
+            vec![ymd(2022, 12, 30), ymd(2022, 12, 31)]
+        );
+        assert_eq!(
+            BankClosureRule::RangeExclusion(ymd(2022, 12, 30), ymd(2023, 1, 2)).dates(2023..=2024),
+            vec![ymd(2023, 1, 1), ymd(2023, 1, 2)]
+        );
+        assert!(
+            BankClosureRule::RangeExclusion(ymd(2022, 1, 2), ymd(2022, 1, 1))
+                .dates(2022..=2022)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn long_ranges_only_cover_the_given_years() {
+        let rule = BankClosureRule::RangeExclusion(ymd(-200_000, 1, 1), ymd(200_000, 12, 31));
+
+        let dates = rule.dates(2024..=2024);
+
+        assert_eq!(dates.len(), 366);
+        assert_eq!(dates.first(), Some(&ymd(2024, 1, 1)));
+        assert_eq!(dates.last(), Some(&ymd(2024, 12, 31)));
+    }
+}
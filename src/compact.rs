@@ -0,0 +1,249 @@
+use std::fmt;
+
+use chrono::{naive::NaiveDate, Duration, Weekday};
+
+use crate::Calendar;
+
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 6;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+impl Calendar {
+    /// Encodes the calendar in a compact binary format, for storing large numbers of calendars.
+    ///
+    /// The format is:
+    /// - 1 byte: format version, currently `1`
+    /// - 1 byte: working days as a bitmask, with Monday as the lowest bit
+    /// - 4 bytes: number of holidays, as a little-endian `u32`
+    /// - 4 bytes per holiday: days since 1970-01-01, as a little-endian `i32`, in ascending order
+    ///
+    /// Holiday names are not encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(1970, 1, 2).unwrap()]);
+    /// assert_eq!(cal.serialize_compact(), vec![1, 0b11111, 1, 0, 0, 0, 1, 0, 0, 0]);
+    /// ```
+    pub fn serialize_compact(&self) -> Vec<u8> {
+        let mut holidays: Vec<_> = self.holidays.iter().map(|&date| epoch_days(date)).collect();
+        holidays.sort_unstable();
+
+        let working_days = self
+            .working_days
+            .iter()
+            .fold(0u8, |mask, day| mask | 1 << day.num_days_from_monday());
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + 4 * holidays.len());
+        bytes.push(VERSION);
+        bytes.push(working_days);
+        bytes.extend_from_slice(&(holidays.len() as u32).to_le_bytes());
+        for days in holidays {
+            bytes.extend_from_slice(&days.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a calendar encoded with [`Calendar::serialize_compact`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CompactError`] if the bytes are not a valid encoding, including if they
+    /// encode no working days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2020, 12, 25).unwrap()]);
+    /// let bytes = cal.serialize_compact();
+    /// assert_eq!(Calendar::deserialize_compact(&bytes).unwrap(), cal);
+    /// ```
+    pub fn deserialize_compact(bytes: &[u8]) -> Result<Calendar, CompactError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(CompactError::UnexpectedEnd);
+        }
+        if bytes[0] != VERSION {
+            return Err(CompactError::UnsupportedVersion(bytes[0]));
+        }
+
+        let mask = bytes[1];
+        if mask == 0 || mask & 0b1000_0000 != 0 {
+            return Err(CompactError::InvalidWorkingDays(mask));
+        }
+        let working_days = WEEKDAYS
+            .iter()
+            .filter(|day| mask & 1 << day.num_days_from_monday() != 0)
+            .cloned()
+            .collect();
+
+        let count = u32::from_le_bytes(bytes[2..HEADER_LEN].try_into().unwrap()) as usize;
+        let body = &bytes[HEADER_LEN..];
+        if body.len() / 4 < count {
+            return Err(CompactError::UnexpectedEnd);
+        }
+        if body.len() > count * 4 {
+            return Err(CompactError::TrailingBytes);
+        }
+
+        let holidays = body
+            .chunks_exact(4)
+            .map(|chunk| {
+                let days = i32::from_le_bytes(chunk.try_into().unwrap());
+                from_epoch_days(days).ok_or(CompactError::InvalidHoliday(days))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Calendar::from_parts(working_days, holidays))
+    }
+}
+
+fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
+fn epoch_days(date: NaiveDate) -> i32 {
+    (date - epoch()).num_days() as i32
+}
+
+fn from_epoch_days(days: i32) -> Option<NaiveDate> {
+    epoch().checked_add_signed(Duration::days(i64::from(days)))
+}
+
+/// Errors returned by [`Calendar::deserialize_compact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompactError {
+    /// The format version is not one this crate can read
+    UnsupportedVersion(u8),
+    /// The input ended before the encoded calendar did
+    UnexpectedEnd,
+    /// The input continued after the encoded calendar ended
+    TrailingBytes,
+    /// The working days bitmask has no weekdays set, or bits set beyond the 7 weekdays
+    InvalidWorkingDays(u8),
+    /// A holiday is outside the range of supported dates
+    InvalidHoliday(i32),
+}
+
+impl fmt::Display for CompactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompactError::UnsupportedVersion(version) => {
+                write!(f, "unsupported compact format version {}", version)
+            }
+            CompactError::UnexpectedEnd => f.write_str("unexpected end of input"),
+            CompactError::TrailingBytes => f.write_str("unexpected bytes after end of calendar"),
+            CompactError::InvalidWorkingDays(mask) => {
+                write!(f, "invalid working days bitmask {:#010b}", mask)
+            }
+            CompactError::InvalidHoliday(days) => {
+                write!(f, "holiday {} days from epoch is out of range", days)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompactError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let cal = Calendar::from_parts(
+            [Weekday::Sun, Weekday::Wed].into_iter().collect(),
+            [
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1850, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let bytes = cal.serialize_compact();
+
+        assert_eq!(bytes.len(), 6 + 3 * 4);
+        assert_eq!(bytes[1], 0b1000100);
+        assert_eq!(Calendar::deserialize_compact(&bytes), Ok(cal));
+    }
+
+    #[test]
+    fn holidays_are_sorted() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(1970, 1, 3).unwrap(),
+            NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+        ]);
+
+        let bytes = cal.serialize_compact();
+
+        assert_eq!(bytes[6..10], (-1i32).to_le_bytes());
+        assert_eq!(bytes[10..14], 2i32.to_le_bytes());
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let mut bytes = Calendar::workweek().serialize_compact();
+        bytes[0] = 2;
+
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes),
+            Err(CompactError::UnsupportedVersion(2))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_and_trailing_input() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()]);
+        let bytes = cal.serialize_compact();
+
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes[..bytes.len() - 1]),
+            Err(CompactError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes[..3]),
+            Err(CompactError::UnexpectedEnd)
+        );
+
+        let mut bytes = bytes;
+        bytes.push(0);
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes),
+            Err(CompactError::TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_working_days() {
+        let bytes = [1, 0b1000_0001, 0, 0, 0, 0];
+
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes),
+            Err(CompactError::InvalidWorkingDays(0b1000_0001))
+        );
+    }
+
+    #[test]
+    fn rejects_no_working_days() {
+        let bytes = [1, 0, 0, 0, 0, 0];
+
+        assert_eq!(
+            Calendar::deserialize_compact(&bytes),
+            Err(CompactError::InvalidWorkingDays(0))
+        );
+    }
+}
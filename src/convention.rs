@@ -0,0 +1,122 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How to adjust a date that does not fall on a business day, as used by
+/// [`Calendar::roll_with_convention`](crate::Calendar::roll_with_convention).
+///
+/// Displays as the standard abbreviation, and parses from either the abbreviation or the full
+/// name, ignoring case.
+///
+/// ```
+/// # use business::RollingConvention;
+/// assert_eq!(RollingConvention::ModifiedFollowing.to_string(), "MOD");
+/// assert_eq!("mod".parse(), Ok(RollingConvention::ModifiedFollowing));
+/// assert_eq!("ModifiedFollowing".parse(), Ok(RollingConvention::ModifiedFollowing));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollingConvention {
+    /// Leave the date as it is, even if it is not a business day
+    Unadjusted,
+    /// Roll forward to the next business day
+    Following,
+    /// Roll forward to the next business day, unless that is in the next month, in which case
+    /// roll backward instead
+    ModifiedFollowing,
+    /// Roll backward to the previous business day
+    Preceding,
+    /// Roll backward to the previous business day, unless that is in the previous month, in
+    /// which case roll forward instead
+    ModifiedPreceding,
+}
+
+const CONVENTIONS: &[(RollingConvention, &str, &str)] = &[
+    (RollingConvention::Unadjusted, "U", "Unadjusted"),
+    (RollingConvention::Following, "FOL", "Following"),
+    (
+        RollingConvention::ModifiedFollowing,
+        "MOD",
+        "ModifiedFollowing",
+    ),
+    (RollingConvention::Preceding, "PRE", "Preceding"),
+    (
+        RollingConvention::ModifiedPreceding,
+        "MOP",
+        "ModifiedPreceding",
+    ),
+];
+
+impl fmt::Display for RollingConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, abbreviation, _) = CONVENTIONS
+            .iter()
+            .find(|(convention, _, _)| convention == self)
+            .unwrap();
+        f.write_str(abbreviation)
+    }
+}
+
+impl FromStr for RollingConvention {
+    type Err = ParseRollingConventionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CONVENTIONS
+            .iter()
+            .find(|(_, abbreviation, name)| {
+                s.eq_ignore_ascii_case(abbreviation) || s.eq_ignore_ascii_case(name)
+            })
+            .map(|&(convention, _, _)| convention)
+            .ok_or_else(|| ParseRollingConventionError(s.to_string()))
+    }
+}
+
+/// Which way to roll a date that does not fall on a business day, as used by
+/// [`Calendar::roll`](crate::Calendar::roll).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollDirection {
+    /// Roll forward to the next business day
+    Forward,
+    /// Roll backward to the previous business day
+    Backward,
+}
+
+/// Error returned when parsing an unknown [`RollingConvention`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRollingConventionError(String);
+
+impl fmt::Display for ParseRollingConventionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown rolling convention: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRollingConventionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_round_trips() {
+        for &(convention, _, _) in CONVENTIONS {
+            assert_eq!(convention.to_string().parse(), Ok(convention));
+        }
+    }
+
+    #[test]
+    fn parse_is_case_insensitive() {
+        assert_eq!("fol".parse(), Ok(RollingConvention::Following));
+        assert_eq!("PRECEDING".parse(), Ok(RollingConvention::Preceding));
+        assert_eq!("u".parse(), Ok(RollingConvention::Unadjusted));
+        assert_eq!(
+            "modifiedpreceding".parse(),
+            Ok(RollingConvention::ModifiedPreceding)
+        );
+    }
+
+    #[test]
+    fn parse_unknown_is_error() {
+        let err = "nearest".parse::<RollingConvention>().unwrap_err();
+
+        assert_eq!(err.to_string(), "unknown rolling convention: \"nearest\"");
+    }
+}
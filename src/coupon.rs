@@ -0,0 +1,308 @@
+use chrono::{naive::NaiveDate, Months};
+
+use crate::{add_months, Calendar, RollingConvention};
+
+/// Where to put the irregular period when a bond's term is not a whole number of coupon
+/// periods, as used by [`Calendar::generate_coupon_dates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StubType {
+    /// A shorter first period, with regular dates counted backward from maturity
+    ShortFirst,
+    /// A longer first period, merging the short first period into the one after it
+    LongFirst,
+    /// A shorter last period, with regular dates counted forward from issue
+    ShortLast,
+    /// A longer last period, merging the short last period into the one before it
+    LongLast,
+}
+
+impl Calendar {
+    /// Generates the coupon payment dates of a bond from `issue_date` to `maturity_date`, with
+    /// `frequency` payments per year. Unadjusted dates are spaced `12 / frequency` months apart,
+    /// counted backward from maturity for a first stub and forward from issue for a last stub.
+    /// Each date is then adjusted with `convention`.
+    ///
+    /// The issue date itself is not a coupon date, and the maturity date always is. Returns an
+    /// empty list if `maturity_date` is not after `issue_date`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frequency` does not divide 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollingConvention, StubType};
+    /// let cal = Calendar::workweek();
+    /// let issue = NaiveDate::from_ymd_opt(2022, 3, 1).unwrap();
+    /// let maturity = NaiveDate::from_ymd_opt(2023, 6, 30).unwrap();
+    ///
+    /// let dates = cal.generate_coupon_dates(
+    ///     issue,
+    ///     maturity,
+    ///     2,
+    ///     RollingConvention::ModifiedFollowing,
+    ///     StubType::ShortFirst,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     dates,
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2022, 6, 30).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2022, 12, 30).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn generate_coupon_dates(
+        &self,
+        issue_date: NaiveDate,
+        maturity_date: NaiveDate,
+        frequency: u32,
+        convention: RollingConvention,
+        stub: StubType,
+    ) -> Vec<NaiveDate> {
+        assert!(
+            frequency > 0 && 12 % frequency == 0,
+            "frequency must divide 12"
+        );
+        let period = 12 / frequency;
+
+        let mut dates = Vec::new();
+        if maturity_date <= issue_date {
+            return dates;
+        }
+
+        match stub {
+            StubType::ShortFirst | StubType::LongFirst => {
+                let mut periods = 0;
+                let mut date = maturity_date;
+                while date > issue_date {
+                    dates.push(date);
+                    periods += 1;
+                    date = maturity_date
+                        .checked_sub_months(Months::new(period * periods))
+                        .expect("date out of range");
+                }
+                dates.reverse();
+                if stub == StubType::LongFirst && date != issue_date && dates.len() > 1 {
+                    dates.remove(0);
+                }
+            }
+            StubType::ShortLast | StubType::LongLast => {
+                let mut periods = 1;
+                let mut date = add_months(issue_date, period);
+                while date < maturity_date {
+                    dates.push(date);
+                    periods += 1;
+                    date = add_months(issue_date, period * periods);
+                }
+                if stub == StubType::LongLast && date != maturity_date {
+                    dates.pop();
+                }
+                dates.push(maturity_date);
+            }
+        }
+
+        dates
+            .into_iter()
+            .map(|date| self.roll_with_convention(date, convention))
+            .collect()
+    }
+
+    /// Generates the rate fixing dates of a floating rate schedule from `start` to `end`, with
+    /// `frequency` periods per year. Periods start every `12 / frequency` months from `start`,
+    /// and each start date is adjusted with `convention` before going back `fixing_lag` business
+    /// days to the date its rate is fixed on.
+    ///
+    /// Returns an empty list if `end` is not after `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frequency` does not divide 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollingConvention};
+    /// let cal = Calendar::workweek();
+    /// let start = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+    ///
+    /// let fixings =
+    ///     cal.generate_fixing_dates(start, end, 2, 2, RollingConvention::ModifiedFollowing);
+    ///
+    /// assert_eq!(
+    ///     fixings,
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2021, 12, 30).unwrap(),
+    ///         // The period starts on Monday the 4th of July
+    ///         NaiveDate::from_ymd_opt(2022, 6, 30).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn generate_fixing_dates(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        frequency: u32,
+        fixing_lag: u32,
+        convention: RollingConvention,
+    ) -> Vec<NaiveDate> {
+        assert!(
+            frequency > 0 && 12 % frequency == 0,
+            "frequency must divide 12"
+        );
+        let period = 12 / frequency;
+
+        let mut fixings = Vec::new();
+        let mut periods = 0;
+        let mut period_start = start;
+        while period_start < end {
+            let adjusted = self.roll_with_convention(period_start, convention);
+            fixings.push(self.subtract_business_days(adjusted, u64::from(fixing_lag)));
+            periods += 1;
+            period_start = add_months(start, period * periods);
+        }
+        fixings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn unadjusted(issue: NaiveDate, maturity: NaiveDate, stub: StubType) -> Vec<NaiveDate> {
+        Calendar::workweek().generate_coupon_dates(
+            issue,
+            maturity,
+            4,
+            RollingConvention::Unadjusted,
+            stub,
+        )
+    }
+
+    #[test]
+    fn stubs_at_front() {
+        let (issue, maturity) = (ymd(2022, 2, 15), ymd(2023, 1, 1));
+
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::ShortFirst),
+            vec![
+                ymd(2022, 4, 1),
+                ymd(2022, 7, 1),
+                ymd(2022, 10, 1),
+                ymd(2023, 1, 1)
+            ]
+        );
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::LongFirst),
+            vec![ymd(2022, 7, 1), ymd(2022, 10, 1), ymd(2023, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn stubs_at_back() {
+        let (issue, maturity) = (ymd(2022, 1, 1), ymd(2022, 11, 15));
+
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::ShortLast),
+            vec![
+                ymd(2022, 4, 1),
+                ymd(2022, 7, 1),
+                ymd(2022, 10, 1),
+                ymd(2022, 11, 15)
+            ]
+        );
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::LongLast),
+            vec![ymd(2022, 4, 1), ymd(2022, 7, 1), ymd(2022, 11, 15)]
+        );
+    }
+
+    #[test]
+    fn regular_schedule_has_no_stub() {
+        let (issue, maturity) = (ymd(2022, 1, 1), ymd(2023, 1, 1));
+        let expected = vec![
+            ymd(2022, 4, 1),
+            ymd(2022, 7, 1),
+            ymd(2022, 10, 1),
+            ymd(2023, 1, 1),
+        ];
+
+        for stub in [
+            StubType::ShortFirst,
+            StubType::LongFirst,
+            StubType::ShortLast,
+            StubType::LongLast,
+        ] {
+            assert_eq!(unadjusted(issue, maturity, stub), expected, "{:?}", stub);
+        }
+    }
+
+    #[test]
+    fn term_shorter_than_a_period_pays_at_maturity() {
+        let (issue, maturity) = (ymd(2022, 1, 1), ymd(2022, 2, 1));
+
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::LongFirst),
+            vec![maturity]
+        );
+        assert_eq!(
+            unadjusted(issue, maturity, StubType::LongLast),
+            vec![maturity]
+        );
+        assert!(unadjusted(maturity, issue, StubType::ShortFirst).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn frequency_not_dividing_12_panics() {
+        Calendar::workweek().generate_coupon_dates(
+            ymd(2022, 1, 1),
+            ymd(2023, 1, 1),
+            5,
+            RollingConvention::Following,
+            StubType::ShortFirst,
+        );
+    }
+
+    #[test]
+    fn fixing_dates_lag_adjusted_period_starts() {
+        let holiday = ymd(2022, 4, 1);
+        let cal = Calendar::with_holidays(&[holiday]);
+
+        // Quarters start on Saturday the 1st of January, a holiday on the 1st of April, and
+        // Friday the 1st of July and Saturday the 1st of October
+        assert_eq!(
+            cal.generate_fixing_dates(
+                ymd(2022, 1, 1),
+                ymd(2023, 1, 1),
+                4,
+                1,
+                RollingConvention::Following
+            ),
+            vec![
+                ymd(2021, 12, 31),
+                ymd(2022, 3, 31),
+                ymd(2022, 6, 30),
+                ymd(2022, 9, 30),
+            ]
+        );
+        assert!(cal
+            .generate_fixing_dates(
+                ymd(2022, 1, 1),
+                ymd(2022, 1, 1),
+                4,
+                2,
+                RollingConvention::Following
+            )
+            .is_empty());
+    }
+}
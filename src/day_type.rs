@@ -0,0 +1,160 @@
+use chrono::{naive::NaiveDate, Datelike, Duration};
+
+use crate::Calendar;
+
+/// What kind of day a date is, as returned by [`Calendar::day_type`]. Weekend days are those
+/// that are not one of the calendar's working days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayType {
+    /// A working day that is not a holiday
+    BusinessDay,
+    /// A working day that is a holiday
+    WorkingWeekdayHoliday,
+    /// A weekend day that is not a holiday
+    WeekendDay,
+    /// A weekend day that is also a holiday
+    WeekendHoliday,
+}
+
+/// Number of days of each [`DayType`] in a range, as returned by [`Calendar::count_by_day_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DayTypeCount {
+    /// Number of [`DayType::BusinessDay`]s
+    pub business_days: u32,
+    /// Number of [`DayType::WorkingWeekdayHoliday`]s
+    pub working_weekday_holidays: u32,
+    /// Number of [`DayType::WeekendDay`]s
+    pub weekend_days: u32,
+    /// Number of [`DayType::WeekendHoliday`]s
+    pub weekend_holidays: u32,
+}
+
+impl DayTypeCount {
+    /// Returns the total number of days counted.
+    pub fn total(&self) -> u32 {
+        self.business_days
+            + self.working_weekday_holidays
+            + self.weekend_days
+            + self.weekend_holidays
+    }
+}
+
+impl Calendar {
+    /// Classifies the date by whether it falls on a working day and whether it is a holiday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Duration, NaiveDate};
+    /// # use business::{Calendar, DayType};
+    /// let xmas = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+    /// let cal = Calendar::with_holidays(&[xmas, boxing_day]);
+    ///
+    /// assert_eq!(cal.day_type(xmas), DayType::WeekendHoliday);
+    /// assert_eq!(cal.day_type(boxing_day), DayType::WorkingWeekdayHoliday);
+    /// assert_eq!(cal.day_type(xmas - Duration::days(1)), DayType::WeekendDay);
+    /// assert_eq!(cal.day_type(xmas - Duration::days(2)), DayType::BusinessDay);
+    /// ```
+    pub fn day_type(&self, date: NaiveDate) -> DayType {
+        let is_working_day = self.working_days.contains(&date.weekday());
+        let is_holiday = self.holidays.contains(&date);
+        match (is_working_day, is_holiday) {
+            (true, false) => DayType::BusinessDay,
+            (true, true) => DayType::WorkingWeekdayHoliday,
+            (false, false) => DayType::WeekendDay,
+            (false, true) => DayType::WeekendHoliday,
+        }
+    }
+
+    /// Returns the [`day_type`](Self::day_type) of each date, in the same order.
+    pub fn day_type_bulk(&self, dates: &[NaiveDate]) -> Vec<DayType> {
+        dates.iter().map(|&date| self.day_type(date)).collect()
+    }
+
+    /// Counts the days of each [`DayType`] in `[start, end]`. The counts add up to the number of
+    /// days in the range, or `0` if `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, DayTypeCount};
+    /// let cal = Calendar::with_holidays(&[
+    ///     NaiveDate::from_ymd_opt(2022, 12, 25).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2022, 12, 26).unwrap(),
+    /// ]);
+    /// let start = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+    ///
+    /// assert_eq!(
+    ///     cal.count_by_day_type(start, end),
+    ///     DayTypeCount {
+    ///         business_days: 21,
+    ///         working_weekday_holidays: 1,
+    ///         weekend_days: 8,
+    ///         weekend_holidays: 1,
+    ///     }
+    /// );
+    /// ```
+    pub fn count_by_day_type(&self, start: NaiveDate, end: NaiveDate) -> DayTypeCount {
+        let mut count = DayTypeCount::default();
+        let mut date = start;
+        while date <= end {
+            match self.day_type(date) {
+                DayType::BusinessDay => count.business_days += 1,
+                DayType::WorkingWeekdayHoliday => count.working_weekday_holidays += 1,
+                DayType::WeekendDay => count.weekend_days += 1,
+                DayType::WeekendHoliday => count.weekend_holidays += 1,
+            }
+            date += Duration::days(1);
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+
+    use super::*;
+
+    #[test]
+    fn business_days_agree_with_is_business_day() {
+        let mut cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 8).unwrap()]);
+        cal.working_days.insert(Weekday::Sat);
+        let dates: Vec<_> = NaiveDate::from_ymd_opt(2022, 10, 3)
+            .unwrap()
+            .iter_days()
+            .take(14)
+            .collect();
+
+        let day_types = cal.day_type_bulk(&dates);
+
+        for (date, day_type) in dates.iter().zip(&day_types) {
+            assert_eq!(
+                *day_type == DayType::BusinessDay,
+                cal.is_business_day(*date),
+                "{}",
+                date
+            );
+        }
+        assert_eq!(day_types[5], DayType::WorkingWeekdayHoliday);
+        assert_eq!(day_types[6], DayType::WeekendDay);
+        assert_eq!(day_types[12], DayType::BusinessDay);
+    }
+
+    #[test]
+    fn day_type_counts_add_up_to_range_length() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()]);
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        let count = cal.count_by_day_type(start, end);
+
+        assert_eq!(count.total(), 366);
+        assert_eq!(count.business_days, cal.business_days_in_year(2024));
+        assert_eq!(count.working_weekday_holidays, 1);
+        assert_eq!(cal.count_by_day_type(end, start), DayTypeCount::default());
+    }
+}
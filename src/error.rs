@@ -0,0 +1,35 @@
+use std::fmt;
+
+use chrono::naive::NaiveDate;
+
+/// Errors returned by fallible [`Calendar`](crate::Calendar) operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    /// The date was required to be a business day, but is not
+    NotABusinessDay(NaiveDate),
+    /// A calendar was given no working days, so it would have no business days at all
+    NoWorkingDays,
+    /// A calendar has fewer working days per week than the required minimum
+    TooFewWorkingDays {
+        /// Number of working days in the calendar
+        found: usize,
+        /// Minimum number of working days required
+        minimum: u32,
+    },
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::NotABusinessDay(date) => write!(f, "{} is not a business day", date),
+            CalendarError::NoWorkingDays => f.write_str("calendar has no working days"),
+            CalendarError::TooFewWorkingDays { found, minimum } => write!(
+                f,
+                "calendar has {} working days, but at least {} are required",
+                found, minimum
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
@@ -0,0 +1,444 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{naive::NaiveDate, Weekday};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_yaml::Value;
+
+use crate::{workweek, Calendar};
+
+const LONG_NAMES: [(Weekday, &str); 7] = [
+    (Weekday::Mon, "Monday"),
+    (Weekday::Tue, "Tuesday"),
+    (Weekday::Wed, "Wednesday"),
+    (Weekday::Thu, "Thursday"),
+    (Weekday::Fri, "Friday"),
+    (Weekday::Sat, "Saturday"),
+    (Weekday::Sun, "Sunday"),
+];
+
+/// How weekdays are written when exporting a calendar with
+/// [`Calendar::to_yaml_string_with_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeekdayFormat {
+    /// `monday`
+    LongLower,
+    /// `Monday`
+    LongTitle,
+    /// `MONDAY`
+    LongUpper,
+    /// `mon`
+    ShortLower,
+    /// `Mon`
+    ShortTitle,
+    /// `MON`
+    ShortUpper,
+    /// ISO 8601 numbering, from `1` for Monday to `7` for Sunday
+    IsoNumeric,
+    /// US numbering, from `1` for Sunday to `7` for Saturday. Exported calendars are marked with
+    /// `weekday_format: us_numeric`, so that their numbers are not read back as ISO 8601.
+    UsNumeric,
+}
+
+impl WeekdayFormat {
+    /// Formats `weekday` as a YAML value, which is a number for the numeric formats and a string
+    /// otherwise.
+    fn value(self, weekday: Weekday) -> Value {
+        let long = LONG_NAMES[weekday.num_days_from_monday() as usize].1;
+        let short = &long[..3];
+        match self {
+            WeekdayFormat::LongLower => Value::from(long.to_lowercase()),
+            WeekdayFormat::LongTitle => Value::from(long),
+            WeekdayFormat::LongUpper => Value::from(long.to_uppercase()),
+            WeekdayFormat::ShortLower => Value::from(short.to_lowercase()),
+            WeekdayFormat::ShortTitle => Value::from(short),
+            WeekdayFormat::ShortUpper => Value::from(short.to_uppercase()),
+            WeekdayFormat::IsoNumeric => Value::from(weekday.number_from_monday()),
+            WeekdayFormat::UsNumeric => Value::from(weekday.number_from_sunday()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FormattedCalendar<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weekday_format: Option<WeekdayFormat>,
+    working_days: Vec<Value>,
+    holidays: Vec<NaiveDate>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    holiday_names: BTreeMap<NaiveDate, HolidayNames<'a>>,
+}
+
+/// The names of one holiday in YAML, written as a single string when there is only one.
+struct HolidayNames<'a>(&'a [String]);
+
+impl Serialize for HolidayNames<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            [name] => name.serialize(serializer),
+            names => names.serialize(serializer),
+        }
+    }
+}
+
+/// Sorts the names of the calendar's holidays by date, leaving out dates that are not holidays.
+fn sorted_holiday_names(cal: &Calendar) -> BTreeMap<NaiveDate, HolidayNames<'_>> {
+    cal.holiday_names
+        .iter()
+        .filter(|(date, names)| cal.holidays.contains(date) && !names.is_empty())
+        .map(|(date, names)| (*date, HolidayNames(names)))
+        .collect()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HolidayNamesRepr {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Deserializes holiday names, each written as a single string or as a list of names.
+fn deserialize_holiday_names<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<NaiveDate, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        HashMap::<NaiveDate, HolidayNamesRepr>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(date, repr)| match repr {
+                HolidayNamesRepr::One(name) => (date, vec![name]),
+                HolidayNamesRepr::Many(names) => (date, names),
+            })
+            .collect(),
+    )
+}
+
+impl Calendar {
+    /// Serializes the calendar to YAML, writing working days in the given format. Working days are
+    /// written from Monday to Sunday, and holidays in date order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, WeekdayFormat};
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2017, 12, 25).unwrap()]);
+    ///
+    /// let yml = cal.to_yaml_string_with_format(WeekdayFormat::IsoNumeric);
+    ///
+    /// assert_eq!(
+    ///     yml,
+    ///     "working_days:\n- 1\n- 2\n- 3\n- 4\n- 5\nholidays:\n- 2017-12-25\n"
+    /// );
+    /// assert_eq!(serde_yaml::from_str::<Calendar>(&yml).unwrap(), cal);
+    /// ```
+    pub fn to_yaml_string_with_format(&self, weekday_fmt: WeekdayFormat) -> String {
+        serde_yaml::to_string(&self.formatted(weekday_fmt))
+            .expect("calendar is always serializable")
+    }
+
+    fn formatted(&self, weekday_fmt: WeekdayFormat) -> FormattedCalendar<'_> {
+        let mut working_days: Vec<_> = self.working_days.iter().cloned().collect();
+        working_days.sort_by_key(|day| day.num_days_from_monday());
+
+        let mut holidays: Vec<_> = self.holidays.iter().cloned().collect();
+        holidays.sort_unstable();
+
+        FormattedCalendar {
+            weekday_format: Some(weekday_fmt).filter(|fmt| *fmt == WeekdayFormat::UsNumeric),
+            working_days: working_days
+                .into_iter()
+                .map(|day| weekday_fmt.value(day))
+                .collect(),
+            holidays,
+            holiday_names: sorted_holiday_names(self),
+        }
+    }
+}
+
+/// Serializes the calendar like [`Calendar::to_yaml_string_with_format`] with
+/// [`WeekdayFormat::ShortTitle`], so the same calendar is always written the same way.
+impl Serialize for Calendar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.formatted(WeekdayFormat::ShortTitle)
+            .serialize(serializer)
+    }
+}
+
+/// A calendar as written in YAML. Working days written as numbers are ISO 8601 numbers, unless
+/// `weekday_format` is `us_numeric`.
+#[derive(Deserialize)]
+pub(crate) struct CalendarRepr {
+    #[serde(default)]
+    weekday_format: Option<WeekdayFormat>,
+    #[serde(
+        default = "default_working_days",
+        deserialize_with = "deserialize_working_days"
+    )]
+    working_days: Vec<WorkingDay>,
+    #[serde(default, deserialize_with = "deserialize_holidays")]
+    holidays: HashSet<NaiveDate>,
+    #[serde(default, deserialize_with = "deserialize_holiday_names")]
+    holiday_names: HashMap<NaiveDate, Vec<String>>,
+}
+
+impl From<CalendarRepr> for Calendar {
+    fn from(repr: CalendarRepr) -> Self {
+        let us_numeric = repr.weekday_format == Some(WeekdayFormat::UsNumeric);
+        let working_days = repr
+            .working_days
+            .into_iter()
+            .map(|day| match day {
+                WorkingDay::Named(weekday) => weekday,
+                WorkingDay::Numbered(number) if us_numeric => {
+                    LONG_NAMES[(number as usize + 5) % 7].0
+                }
+                WorkingDay::Numbered(number) => LONG_NAMES[number as usize - 1].0,
+            })
+            .collect();
+
+        let mut cal = Calendar::from_parts(working_days, repr.holidays);
+        cal.holiday_names = repr.holiday_names;
+        cal.holiday_names
+            .retain(|date, names| cal.holidays.contains(date) && !names.is_empty());
+        cal
+    }
+}
+
+/// A working day as written in YAML. Numbers, from `1` to `7`, are kept as they are until the
+/// calendar's `weekday_format` is known.
+enum WorkingDay {
+    Named(Weekday),
+    Numbered(u32),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WeekdayRepr {
+    Number(u32),
+    Name(String),
+}
+
+fn default_working_days() -> Vec<WorkingDay> {
+    workweek().into_iter().map(WorkingDay::Named).collect()
+}
+
+/// Deserializes working days written as names in any case, short or long, or as numbers.
+fn deserialize_working_days<'de, D>(deserializer: D) -> Result<Vec<WorkingDay>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<WeekdayRepr>::deserialize(deserializer)?
+        .into_iter()
+        .map(|repr| match repr {
+            WeekdayRepr::Number(number) => weekday_number(number),
+            WeekdayRepr::Name(name) => match name.parse::<u32>() {
+                Ok(number) => weekday_number(number),
+                Err(_) => name
+                    .parse::<Weekday>()
+                    .map(WorkingDay::Named)
+                    .map_err(|_| de::Error::custom(format!("invalid weekday: {}", name))),
+            },
+        })
+        .collect()
+}
+
+/// A holiday entry in YAML, either a single date or an inclusive ISO 8601 range written as
+/// `start/end`.
+#[derive(Debug, PartialEq)]
+enum CalendarEntry {
+    SingleDate(NaiveDate),
+    DateRange(NaiveDate, NaiveDate),
+}
+
+impl<'de> Deserialize<'de> for CalendarEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entry = String::deserialize(deserializer)?;
+        let parse = |date: &str| {
+            date.trim()
+                .parse::<NaiveDate>()
+                .map_err(|_| de::Error::custom(format!("invalid holiday: {}", entry)))
+        };
+        match entry.split_once('/') {
+            None => Ok(CalendarEntry::SingleDate(parse(&entry)?)),
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if end < start {
+                    return Err(de::Error::custom(format!(
+                        "holiday range ends before it starts: {}",
+                        entry
+                    )));
+                }
+                Ok(CalendarEntry::DateRange(start, end))
+            }
+        }
+    }
+}
+
+/// Deserializes holidays, expanding date ranges into every date they cover.
+fn deserialize_holidays<'de, D>(deserializer: D) -> Result<HashSet<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut holidays = HashSet::new();
+    for entry in Vec::<CalendarEntry>::deserialize(deserializer)? {
+        match entry {
+            CalendarEntry::SingleDate(date) => {
+                holidays.insert(date);
+            }
+            CalendarEntry::DateRange(start, end) => {
+                holidays.extend(start.iter_days().take_while(|date| *date <= end));
+            }
+        }
+    }
+    Ok(holidays)
+}
+
+fn weekday_number<E: de::Error>(number: u32) -> Result<WorkingDay, E> {
+    match number {
+        1..=7 => Ok(WorkingDay::Numbered(number)),
+        _ => Err(E::custom(format!("invalid weekday number: {}", number))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weekend_calendar() -> Calendar {
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let mut cal = Calendar::with_holidays(&[xmas]);
+        cal.working_days = [Weekday::Sat, Weekday::Sun].into_iter().collect();
+        cal.add_holiday_name(xmas, "Christmas Day");
+        cal
+    }
+
+    #[test]
+    fn formats_every_weekday_style() {
+        let cal = weekend_calendar();
+        let cases = [
+            (WeekdayFormat::LongLower, "- saturday\n- sunday\n"),
+            (WeekdayFormat::LongTitle, "- Saturday\n- Sunday\n"),
+            (WeekdayFormat::LongUpper, "- SATURDAY\n- SUNDAY\n"),
+            (WeekdayFormat::ShortLower, "- sat\n- sun\n"),
+            (WeekdayFormat::ShortTitle, "- Sat\n- Sun\n"),
+            (WeekdayFormat::ShortUpper, "- SAT\n- SUN\n"),
+            (WeekdayFormat::IsoNumeric, "- 6\n- 7\n"),
+            (WeekdayFormat::UsNumeric, "- 7\n- 1\n"),
+        ];
+
+        for (fmt, days) in cases {
+            let marker = match fmt {
+                WeekdayFormat::UsNumeric => "weekday_format: us_numeric\n",
+                _ => "",
+            };
+            let expected = format!(
+                "{}working_days:\n{}holidays:\n- 2017-12-25\nholiday_names:\n  2017-12-25: Christmas Day\n",
+                marker, days
+            );
+            assert_eq!(cal.to_yaml_string_with_format(fmt), expected, "{:?}", fmt);
+        }
+    }
+
+    #[test]
+    fn round_trips_every_format() {
+        let mut cal = weekend_calendar();
+        cal.add_holiday_name(
+            NaiveDate::from_ymd_opt(2017, 12, 25).unwrap(),
+            "Feast of the Nativity",
+        );
+        let formats = [
+            WeekdayFormat::LongLower,
+            WeekdayFormat::LongTitle,
+            WeekdayFormat::LongUpper,
+            WeekdayFormat::ShortLower,
+            WeekdayFormat::ShortTitle,
+            WeekdayFormat::ShortUpper,
+            WeekdayFormat::IsoNumeric,
+            WeekdayFormat::UsNumeric,
+        ];
+
+        for fmt in formats {
+            let yml = cal.to_yaml_string_with_format(fmt);
+            assert_eq!(
+                serde_yaml::from_str::<Calendar>(&yml).unwrap(),
+                cal,
+                "{:?}",
+                fmt
+            );
+        }
+    }
+
+    #[test]
+    fn reads_quoted_numbers_as_iso() {
+        let cal: Calendar = serde_yaml::from_str("working_days: ['1', 7]\nholidays: []").unwrap();
+
+        assert_eq!(
+            cal.working_days,
+            [Weekday::Mon, Weekday::Sun].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn reads_numbers_as_us_when_marked() {
+        let cal: Calendar =
+            serde_yaml::from_str("weekday_format: us_numeric\nworking_days: [1, '7', sat]")
+                .unwrap();
+
+        assert_eq!(
+            cal.working_days,
+            [Weekday::Sun, Weekday::Sat].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_numbers() {
+        let result = serde_yaml::from_str::<Calendar>("working_days: [0]\nholidays: []");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn expands_holiday_ranges_over_weekends() {
+        let cal: Calendar =
+            serde_yaml::from_str("holidays: [2024-12-20/2024-12-24, 2024-12-31]").unwrap();
+
+        let expected = Calendar::with_holidays(
+            &[20, 21, 22, 23, 24, 31].map(|day| NaiveDate::from_ymd_opt(2024, 12, day).unwrap()),
+        );
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn rejects_backwards_holiday_ranges() {
+        let err =
+            serde_yaml::from_str::<Calendar>("holidays: [2024-12-27/2024-12-23]").unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("holidays: holiday range ends before it starts: 2024-12-27/2024-12-23"));
+    }
+
+    #[test]
+    fn writes_several_holiday_names_as_a_list() {
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let mut cal = weekend_calendar();
+        cal.add_holiday_name(xmas, "Feast of the Nativity");
+
+        let yml = cal.to_yaml_string_with_format(WeekdayFormat::ShortLower);
+
+        assert!(yml.ends_with(
+            "holiday_names:\n  2017-12-25:\n  - Christmas Day\n  - Feast of the Nativity\n"
+        ));
+        let read: Calendar = serde_yaml::from_str(&yml).unwrap();
+        assert_eq!(
+            read.holiday_names_on(xmas),
+            vec!["Christmas Day", "Feast of the Nativity"]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use chrono::naive::NaiveDate;
+
+use crate::{workweek, Calendar};
+
+impl Calendar {
+    /// Builds a calendar with Mon-Fri as working days from the all-day events of an iCalendar
+    /// (`.ics`) feed, such as those published by national holiday authorities. Every day an event
+    /// covers becomes a holiday, named after the event's `SUMMARY` if it has one. Days
+    /// covered by several events keep every name, see [`Calendar::holiday_names_on`].
+    ///
+    /// `DTEND` is exclusive, as in iCalendar, and an event without one covers only its start
+    /// date. Events with a date-time start cover the date they start on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ICalendarError`] if an event has no start, a date is invalid, or an event is
+    /// never closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let ics = "\
+    /// BEGIN:VCALENDAR\r
+    /// BEGIN:VEVENT\r
+    /// DTSTART;VALUE=DATE:20241225\r
+    /// DTEND;VALUE=DATE:20241227\r
+    /// SUMMARY:Christmas\r
+    /// END:VEVENT\r
+    /// END:VCALENDAR\r
+    /// ";
+    ///
+    /// let cal = Calendar::from_icalendar(ics).unwrap();
+    ///
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// assert_eq!(cal.holidays, [xmas, boxing_day].into_iter().collect());
+    /// assert_eq!(cal.holiday_name(boxing_day).unwrap(), "Christmas");
+    /// ```
+    pub fn from_icalendar(ics: &str) -> Result<Calendar, ICalendarError> {
+        let mut cal = Calendar::from_parts(workweek(), Default::default());
+        let mut event: Option<Event> = None;
+
+        for line in unfold(ics) {
+            let (name, value) = match line.split_once(':') {
+                Some((property, value)) => {
+                    let name = property.split(';').next().unwrap_or(property);
+                    (name.to_ascii_uppercase(), value)
+                }
+                None => continue,
+            };
+
+            match (name.as_str(), event.as_mut()) {
+                ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                    event = Some(Event::default());
+                }
+                ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                    let Event {
+                        start,
+                        end,
+                        summary,
+                    } = event.take().unwrap();
+                    let start = start.ok_or(ICalendarError::MissingStart)?;
+                    let end = end
+                        .filter(|end| *end > start)
+                        .unwrap_or(start.succ_opt().unwrap());
+                    for date in start.iter_days().take_while(|date| *date < end) {
+                        cal.holidays.insert(date);
+                        if let Some(summary) = &summary {
+                            cal.add_holiday_name(date, summary);
+                        }
+                    }
+                }
+                ("DTSTART", Some(event)) => event.start = Some(parse_date(value)?),
+                ("DTEND", Some(event)) => event.end = Some(parse_date(value)?),
+                ("SUMMARY", Some(event)) => event.summary = Some(unescape(value)),
+                _ => {}
+            }
+        }
+
+        if event.is_some() {
+            return Err(ICalendarError::UnterminatedEvent);
+        }
+
+        Ok(cal)
+    }
+}
+
+#[derive(Default)]
+struct Event {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    summary: Option<String>,
+}
+
+/// Joins folded content lines, which continue on the next line after a space or tab.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Parses the date of a `DATE` or `DATE-TIME` value, e.g. `20241225` or `20241225T090000Z`.
+fn parse_date(value: &str) -> Result<NaiveDate, ICalendarError> {
+    value
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| ICalendarError::InvalidDate(value.to_string()))
+}
+
+/// Unescapes a `TEXT` value in a single pass, so an escaped backslash is never read as the start
+/// of another escape.
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Error returned by [`Calendar::from_icalendar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ICalendarError {
+    /// An event has no `DTSTART`
+    MissingStart,
+    /// A `DTSTART` or `DTEND` is not a valid date
+    InvalidDate(String),
+    /// An event is missing its `END:VEVENT`
+    UnterminatedEvent,
+}
+
+impl fmt::Display for ICalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ICalendarError::MissingStart => f.write_str("event has no start date"),
+            ICalendarError::InvalidDate(value) => write!(f, "invalid event date {:?}", value),
+            ICalendarError::UnterminatedEvent => f.write_str("event is never closed"),
+        }
+    }
+}
+
+impl std::error::Error for ICalendarError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_folded_lines_and_date_times() {
+        let ics = "BEGIN:VCALENDAR\n\
+                   BEGIN:VEVENT\n\
+                   DTSTART:20240101T000000Z\n\
+                   SUMMARY:New Year\\, \n\
+                   \tobserved\n\
+                   END:VEVENT\n\
+                   END:VCALENDAR\n";
+
+        let cal = Calendar::from_icalendar(ics).unwrap();
+
+        let new_year = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(cal.holidays, [new_year].into_iter().collect());
+        assert_eq!(cal.holiday_name(new_year).unwrap(), "New Year, observed");
+    }
+
+    #[test]
+    fn rejects_malformed_events() {
+        assert_eq!(
+            Calendar::from_icalendar("BEGIN:VEVENT\nSUMMARY:Holiday\nEND:VEVENT\n"),
+            Err(ICalendarError::MissingStart)
+        );
+        assert_eq!(
+            Calendar::from_icalendar("BEGIN:VEVENT\nDTSTART;VALUE=DATE:20241325\nEND:VEVENT\n"),
+            Err(ICalendarError::InvalidDate("20241325".to_string()))
+        );
+        assert_eq!(
+            Calendar::from_icalendar("BEGIN:VEVENT\nDTSTART;VALUE=DATE:20241225\n"),
+            Err(ICalendarError::UnterminatedEvent)
+        );
+    }
+
+    #[test]
+    fn unescapes_text_in_one_pass() {
+        assert_eq!(unescape(r"a\\nb"), r"a\nb");
+        assert_eq!(unescape(r"a\nb\Nc"), "a\nb\nc");
+        assert_eq!(unescape(r"one\, two\; three\\"), r"one, two; three\");
+        assert_eq!(unescape(r"trailing\"), r"trailing\");
+    }
+}
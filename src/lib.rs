@@ -9,37 +9,48 @@
 //! ```
 //! use chrono::NaiveDate;
 //!
-//! let xmas = NaiveDate::from_ymd(2020, 12, 25); // Friday
+//! let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap(); // Friday
 //!
 //! let cal = business::Calendar::with_holidays(&[xmas]);
 //!
 //! assert_eq!(cal.is_business_day(xmas), false);
 //!
 //! // The earliest business day
-//! assert_eq!(cal.roll_forward(xmas), NaiveDate::from_ymd(2020, 12, 28));
+//! assert_eq!(cal.business_day_on_or_after(xmas), NaiveDate::from_ymd_opt(2020, 12, 28).unwrap());
 //!
-//! let xmas_eve = NaiveDate::from_ymd(2020, 12, 24);
+//! let xmas_eve = NaiveDate::from_ymd_opt(2020, 12, 24).unwrap();
 //! assert_eq!(cal.is_business_day(xmas_eve), true);
 //!
 //! // Skips over weekend and business holidays
-//! assert_eq!(cal.add_business_days(xmas_eve, 2), NaiveDate::from_ymd(2020, 12, 29));
+//! assert_eq!(cal.add_business_days(xmas_eve, 2), NaiveDate::from_ymd_opt(2020, 12, 29).unwrap());
 //! ```
 //!
 //! # Building a `Calendar` from YAML
 //!
 //! The YAML has to be in the following format:
 //! ```yaml
-//! # Defaults to Mon-Fri if omitted
+//! # Optional, set to us_numeric when working days are numbered from 1 for Sunday
+//! weekday_format: us_numeric
+//! # Defaults to Mon-Fri if omitted. Names are case-insensitive and may be
+//! # abbreviated, e.g. Mon, or written as ISO 8601 numbers, e.g. 1
 //! working_days:
 //!   - monday
 //!   - tuesday
 //!   - wednesday
 //!   - thursday
 //!   - friday
-//! # ISO 8601 dates, defaults to no holidays if omitted
+//! # ISO 8601 dates, defaults to no holidays if omitted. A range of dates,
+//! # inclusive of both ends, can be written as start/end
 //! holidays:
 //!   - 2017-12-25
 //!   - 2017-12-26
+//!   - 2018-08-13/2018-08-17
+//! # Optional names for the holidays above, as a list for holidays with more than one
+//! holiday_names:
+//!   2017-12-25:
+//!     - Christmas Day
+//!     - Feast of the Nativity
+//!   2017-12-26: Boxing Day
 //! ```
 //! A calendar can be built as such:
 //! ```
@@ -48,11 +59,61 @@
 //! let cal: Calendar = serde_yaml::from_str(&yml).unwrap();
 //! ```
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::RangeInclusive;
 
-use chrono::{naive::NaiveDate, Datelike, Duration, Weekday};
+use chrono::{naive::NaiveDate, Datelike, Duration, Local, Months, Weekday};
 use serde::Deserialize;
 
+mod boundary;
+mod builder;
+mod closure;
+mod compact;
+mod convention;
+mod coupon;
+mod day_type;
+mod error;
+mod format;
+mod icalendar;
+mod load;
+#[macro_use]
+mod macros;
+mod period;
+mod position;
+#[cfg(feature = "cached")]
+mod prefix_cache;
+mod recurrence;
+mod schedule;
+mod tenor;
+#[cfg(feature = "thread-cache")]
+mod thread_cache;
+mod timeseries;
+
+pub use boundary::BoundaryType;
+pub use builder::CalendarBuilder;
+pub use closure::BankClosureRule;
+pub use compact::CompactError;
+pub use convention::{ParseRollingConventionError, RollDirection, RollingConvention};
+pub use coupon::StubType;
+pub use day_type::{DayType, DayTypeCount};
+pub use error::CalendarError;
+pub use format::WeekdayFormat;
+pub use icalendar::ICalendarError;
+pub use load::{DirectoryLoadError, LoadError};
+pub use period::{CalendarPeriod, PeriodType};
+pub use position::BusinessDayPosition;
+#[cfg(feature = "cached")]
+pub use prefix_cache::PrefixSumCachedCalendar;
+pub use recurrence::{InvalidDayPolicy, RecurringHoliday};
+pub use schedule::ScheduleValidationError;
+pub use tenor::Tenor;
+#[cfg(feature = "thread-cache")]
+pub use thread_cache::ThreadLocalCachedCalendar;
+pub use timeseries::{BusinessDayTimeSeries, TsError};
+
+#[doc(hidden)]
+pub use chrono as __chrono;
+
 const WORKWEEK: &[Weekday] = &[
     Weekday::Mon,
     Weekday::Tue,
@@ -66,47 +127,78 @@ const WORKWEEK: &[Weekday] = &[
 /// ```
 /// use chrono::NaiveDate;
 ///
-/// let xmas = NaiveDate::from_ymd(2020, 12, 25); // Friday
+/// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap(); // Friday
 ///
 /// let cal = business::Calendar::with_holidays(&[xmas]);
 ///
 /// assert_eq!(cal.is_business_day(xmas), false);
 ///
 /// // The earliest business day
-/// assert_eq!(cal.roll_forward(xmas), NaiveDate::from_ymd(2020, 12, 28));
+/// assert_eq!(cal.business_day_on_or_after(xmas), NaiveDate::from_ymd_opt(2020, 12, 28).unwrap());
 ///
-/// let xmas_eve = NaiveDate::from_ymd(2020, 12, 24);
+/// let xmas_eve = NaiveDate::from_ymd_opt(2020, 12, 24).unwrap();
 /// assert_eq!(cal.is_business_day(xmas_eve), true);
 ///
 /// // Skips over weekend and business holidays
-/// assert_eq!(cal.add_business_days(xmas_eve, 2), NaiveDate::from_ymd(2020, 12, 29));
+/// assert_eq!(cal.add_business_days(xmas_eve, 2), NaiveDate::from_ymd_opt(2020, 12, 29).unwrap());
+/// ```
+///
+/// # Constructing a calendar
+///
+/// Holiday names are kept in a private field, so a `Calendar` can no longer be written as a
+/// struct literal such as `Calendar { working_days, holidays }`. Use
+/// [`Calendar::with_holidays`] for Mon-Fri calendars, [`CalendarBuilder`] to choose working days
+/// as well, or `Calendar::try_from((working_days, holidays))`. The `working_days` and `holidays`
+/// fields are still public, and can be changed after construction.
+///
+/// ```
+/// # use chrono::{NaiveDate, Weekday};
+/// # use business::Calendar;
+/// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+/// let cal = Calendar::try_from((vec![Weekday::Sat, Weekday::Sun], vec![xmas])).unwrap();
+///
+/// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2020, 12, 26).unwrap()), true);
 /// ```
+///
+/// # Supported dates
+///
+/// Any date representable by [`NaiveDate`] can be used, including dates before 1970 and before
+/// the common era, since `NaiveDate` follows the proleptic Gregorian calendar. Calculations that
+/// step past the limits of `NaiveDate` (around 262,000 years either side of the common era) will
+/// panic. In YAML, years below 1000 are written with leading zeros, e.g. `0999-12-25`, and years
+/// before the common era with a leading minus sign.
 #[derive(Debug, PartialEq, Deserialize)]
+#[serde(from = "format::CalendarRepr")]
 pub struct Calendar {
     /// Working days of the week
-    #[serde(default = "workweek")]
     pub working_days: HashSet<Weekday>,
     /// Holiday dates, regardless of the day of the week
     pub holidays: HashSet<NaiveDate>,
+    /// Names of holidays, by date, first name first. Names for dates that are not in
+    /// `holidays` are ignored.
+    holiday_names: HashMap<NaiveDate, Vec<String>>,
 }
 
 impl Calendar {
+    /// Creates a calendar without any holiday names.
+    fn from_parts(working_days: HashSet<Weekday>, holidays: HashSet<NaiveDate>) -> Calendar {
+        Calendar {
+            working_days,
+            holidays,
+            holiday_names: HashMap::new(),
+        }
+    }
+
     /// Creates a `Calendar` with Mon-Fri as working days and no holidays.
     pub fn workweek() -> Calendar {
-        Self {
-            working_days: workweek(),
-            holidays: HashSet::new(),
-        }
+        Calendar::from_parts(workweek(), HashSet::new())
     }
 
     /// Creates a `Calendar` with Mon-Fri as working days and the specified holidays.
     pub fn with_holidays(holidays: &[NaiveDate]) -> Calendar {
         let holidays: HashSet<_> = holidays.iter().cloned().collect();
 
-        Self {
-            working_days: workweek(),
-            holidays,
-        }
+        Calendar::from_parts(workweek(), holidays)
     }
 
     /// Returns `true` if the date is a working day and not a holiday.
@@ -116,12 +208,12 @@ impl Calendar {
     /// ```
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
-    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd(2020, 12, 25)]);
-    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd(2020, 12, 25)), false);
-    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd(2020, 12, 24)), true);
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2020, 12, 25).unwrap()]);
+    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2020, 12, 25).unwrap()), false);
+    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2020, 12, 24).unwrap()), true);
     ///
     /// // Saturday
-    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd(2020, 12, 26)), false);
+    /// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2020, 12, 26).unwrap()), false);
     /// ```
     pub fn is_business_day(&self, date: NaiveDate) -> bool {
         let is_working_day = self.working_days.contains(&date.weekday());
@@ -129,17 +221,57 @@ impl Calendar {
         is_working_day && !is_holiday
     }
 
+    /// Returns the earliest business day on or after the given date. If the date is already a
+    /// business day, the same date will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.business_day_on_or_after(sat), mon);
+    /// assert_eq!(cal.business_day_on_or_after(mon), mon);
+    /// ```
+    #[inline]
+    pub fn business_day_on_or_after(&self, date: NaiveDate) -> NaiveDate {
+        self.roll_forward(date)
+    }
+
+    /// Returns the latest business day on or before the given date. If the date is already a
+    /// business day, the same date will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// assert_eq!(cal.business_day_on_or_before(sun), fri);
+    /// assert_eq!(cal.business_day_on_or_before(fri), fri);
+    /// ```
+    #[inline]
+    pub fn business_day_on_or_before(&self, date: NaiveDate) -> NaiveDate {
+        self.roll_backward(date)
+    }
+
     /// Rolls forward to the next business day. If the date is already a business day,
     /// the same date will be returned.
     ///
+    /// This is the conventional name for [`Calendar::business_day_on_or_after`].
+    ///
     /// # Examples
     ///
     /// ```
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let sat = NaiveDate::from_ymd(2022, 10, 1);
-    /// let mon = NaiveDate::from_ymd(2022, 10, 3);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
     /// assert_eq!(cal.roll_forward(sat), mon);
     /// assert_eq!(cal.roll_forward(mon), mon);
     /// ```
@@ -154,14 +286,16 @@ impl Calendar {
     /// Rolls backward to the previous business day. If the date is already a business day,
     /// the same date will be returned.
     ///
+    /// This is the conventional name for [`Calendar::business_day_on_or_before`].
+    ///
     /// # Examples
     ///
     /// ```
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let fri = NaiveDate::from_ymd(2022, 9, 30);
-    /// let sun = NaiveDate::from_ymd(2022, 10, 2);
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
     /// assert_eq!(cal.roll_backward(sun), fri);
     /// assert_eq!(cal.roll_backward(fri), fri);
     /// ```
@@ -173,6 +307,30 @@ impl Calendar {
         result
     }
 
+    /// Rolls the date to a business day in the given direction, as with
+    /// [`roll_forward`](Self::roll_forward) or [`roll_backward`](Self::roll_backward). Business
+    /// days are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollDirection};
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.roll(sat, RollDirection::Forward), mon);
+    /// assert_eq!(cal.roll(sat, RollDirection::Backward), fri);
+    /// assert_eq!(cal.roll(fri, RollDirection::Forward), fri);
+    /// ```
+    pub fn roll(&self, date: NaiveDate, direction: RollDirection) -> NaiveDate {
+        match direction {
+            RollDirection::Forward => self.roll_forward(date),
+            RollDirection::Backward => self.roll_backward(date),
+        }
+    }
+
     /// Rolls forward to the next business day regardless of whether the given
     /// date is already a business day.
     ///
@@ -182,9 +340,9 @@ impl Calendar {
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let sat = NaiveDate::from_ymd(2022, 10, 1);
-    /// let mon = NaiveDate::from_ymd(2022, 10, 3);
-    /// let tue = NaiveDate::from_ymd(2022, 10, 4);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
     /// assert_eq!(cal.next_business_day(sat), mon);
     /// assert_eq!(cal.next_business_day(mon), tue);
     /// ```
@@ -208,9 +366,9 @@ impl Calendar {
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let thu = NaiveDate::from_ymd(2022, 9, 29);
-    /// let fri = NaiveDate::from_ymd(2022, 9, 30);
-    /// let sun = NaiveDate::from_ymd(2022, 10, 2);
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
     /// assert_eq!(cal.previous_business_day(sun), fri);
     /// assert_eq!(cal.previous_business_day(fri), thu);
     /// ```
@@ -225,6 +383,30 @@ impl Calendar {
         result
     }
 
+    /// Returns the closest business days strictly before and after the given date. For a
+    /// non-business day, these are the same as [`roll_backward`](Self::roll_backward) and
+    /// [`roll_forward`](Self::roll_forward).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// assert_eq!(cal.business_day_fence(sat), (fri, mon));
+    /// assert_eq!(cal.business_day_fence(mon), (fri, tue));
+    /// ```
+    pub fn business_day_fence(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (
+            self.previous_business_day(date),
+            self.next_business_day(date),
+        )
+    }
+
     /// Adds business days to the given date. If the date is not a business day, counting will
     /// start from the next business day.
     ///
@@ -234,15 +416,15 @@ impl Calendar {
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let fri = NaiveDate::from_ymd(2022, 9, 30);
-    /// let sun = NaiveDate::from_ymd(2022, 10, 2);
-    /// let mon = NaiveDate::from_ymd(2022, 10, 3);
-    /// let tue = NaiveDate::from_ymd(2022, 10, 4);
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
     /// assert_eq!(cal.add_business_days(mon, 1), tue);
     /// assert_eq!(cal.add_business_days(fri, 1), mon);
     /// assert_eq!(cal.add_business_days(sun, 1), tue);
     /// ```
-    pub fn add_business_days(&self, date: NaiveDate, delta: u32) -> NaiveDate {
+    pub fn add_business_days(&self, date: NaiveDate, delta: u64) -> NaiveDate {
         let mut result = self.roll_forward(date);
         for _ in 0..delta {
             result = self.next_business_day(result);
@@ -259,214 +441,4809 @@ impl Calendar {
     /// # use chrono::NaiveDate;
     /// # use business::Calendar;
     /// let cal = Calendar::workweek();
-    /// let thu = NaiveDate::from_ymd(2022, 9, 29);
-    /// let fri = NaiveDate::from_ymd(2022, 9, 30);
-    /// let sun = NaiveDate::from_ymd(2022, 10, 2);
-    /// let mon = NaiveDate::from_ymd(2022, 10, 3);
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
     /// assert_eq!(cal.subtract_business_days(fri, 1), thu);
     /// assert_eq!(cal.subtract_business_days(mon, 1), fri);
     /// assert_eq!(cal.subtract_business_days(sun, 1), thu);
     /// ```
-    pub fn subtract_business_days(&self, date: NaiveDate, delta: u32) -> NaiveDate {
+    pub fn subtract_business_days(&self, date: NaiveDate, delta: u64) -> NaiveDate {
         let mut result = self.roll_backward(date);
         for _ in 0..delta {
             result = self.previous_business_day(result);
         }
         result
     }
-}
-
-fn workweek() -> HashSet<Weekday> {
-    WORKWEEK.iter().cloned().collect()
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Counts the runs of consecutive non-business days immediately before and after the given
+    /// date, returning `(preceding, following)`.
+    ///
+    /// If the date is not a business day itself, it is included in the `following` count, so the
+    /// two counts add up to the length of the break the date falls in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.adjacent_non_business_days(fri), (0, 2));
+    /// assert_eq!(cal.adjacent_non_business_days(sat), (0, 2));
+    /// assert_eq!(cal.adjacent_non_business_days(mon), (2, 0));
+    /// ```
+    pub fn adjacent_non_business_days(&self, date: NaiveDate) -> (u32, u32) {
+        let mut preceding = 0;
+        let mut day = date - Duration::days(1);
+        while !self.is_business_day(day) {
+            preceding += 1;
+            day -= Duration::days(1);
+        }
 
-    #[test]
-    fn sat_is_not_business() {
-        let cal = Calendar::workweek();
-        let saturday = NaiveDate::from_ymd(2022, 10, 01);
+        let mut following = 0;
+        let mut day = if self.is_business_day(date) {
+            date + Duration::days(1)
+        } else {
+            date
+        };
+        while !self.is_business_day(day) {
+            following += 1;
+            day += Duration::days(1);
+        }
 
-        assert_eq!(cal.is_business_day(saturday), false);
+        (preceding, following)
     }
 
-    #[test]
-    fn mon_is_business() {
-        let cal = Calendar::workweek();
-        let monday = NaiveDate::from_ymd(2022, 10, 03);
-
-        assert_eq!(cal.is_business_day(monday), true);
+    /// Returns the period spanning the given month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the month is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let feb = cal.for_month(2024, 2);
+    /// assert_eq!(feb.start(), NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+    /// assert_eq!(feb.end(), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    /// ```
+    pub fn for_month(&self, year: i32, month: u32) -> CalendarPeriod<'_> {
+        assert!((1..=12).contains(&month), "month must be between 1 and 12");
+        CalendarPeriod::new(
+            self,
+            first_day_of_month(year, month),
+            last_day_of_month(year, month),
+        )
     }
 
-    #[test]
-    fn mon_holiday_is_not_business() {
-        let monday = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::with_holidays(&[monday]);
-
-        assert_eq!(cal.is_business_day(monday), false);
+    /// Returns the period spanning the given quarter, where quarter 1 is January to March.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quarter is not between 1 and 4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let q1 = cal.for_quarter(2024, 1);
+    /// assert_eq!(q1.start(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    /// assert_eq!(q1.end(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+    /// ```
+    pub fn for_quarter(&self, year: i32, quarter: u32) -> CalendarPeriod<'_> {
+        assert!(
+            (1..=4).contains(&quarter),
+            "quarter must be between 1 and 4"
+        );
+        let first_month = (quarter - 1) * 3 + 1;
+        CalendarPeriod::new(
+            self,
+            first_day_of_month(year, first_month),
+            last_day_of_month(year, first_month + 2),
+        )
     }
 
-    #[test]
-    fn sat_rolls_forward_to_tues() {
-        let sat = NaiveDate::from_ymd(2022, 10, 01);
-        let holiday_mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::with_holidays(&[holiday_mon]);
-
-        let business_tue = NaiveDate::from_ymd(2022, 10, 04);
-
-        assert_eq!(cal.roll_forward(sat), business_tue);
+    /// Returns the period spanning the given year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let year = cal.for_year_period(2024);
+    /// assert_eq!(year.start(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    /// assert_eq!(year.end(), NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+    /// ```
+    pub fn for_year_period(&self, year: i32) -> CalendarPeriod<'_> {
+        CalendarPeriod::new(
+            self,
+            first_day_of_month(year, 1),
+            last_day_of_month(year, 12),
+        )
     }
 
-    #[test]
-    fn mon_rolls_forward_same_day() {
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::workweek();
-
-        assert_eq!(cal.roll_forward(mon), mon);
+    /// Returns the first business day of the period after the one containing `date`. Weeks are
+    /// ISO weeks, starting on Monday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, PeriodType};
+    /// let cal = Calendar::workweek();
+    /// let date = NaiveDate::from_ymd_opt(2022, 9, 14).unwrap();
+    /// let next_month = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let next_year = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    /// assert_eq!(cal.advance_to_next_period_start(date, PeriodType::Quarter), next_month);
+    /// assert_eq!(cal.advance_to_next_period_start(date, PeriodType::Year), next_year);
+    /// ```
+    pub fn advance_to_next_period_start(&self, date: NaiveDate, period: PeriodType) -> NaiveDate {
+        let start = match period {
+            PeriodType::Week => week_start(date) + Duration::weeks(1),
+            PeriodType::Month => add_months(first_day_of_month(date.year(), date.month()), 1),
+            PeriodType::Quarter => {
+                let first_month = (date.month() - 1) / 3 * 3 + 1;
+                add_months(first_day_of_month(date.year(), first_month), 3)
+            }
+            PeriodType::Year => first_day_of_month(date.year() + 1, 1),
+        };
+        self.roll_forward(start)
     }
 
-    #[test]
-    fn sun_rolls_backward_to_thu() {
-        let sun = NaiveDate::from_ymd(2022, 10, 02);
-        let holiday_fri = NaiveDate::from_ymd(2022, 09, 30);
-        let cal = Calendar::with_holidays(&[holiday_fri]);
-
-        let business_thu = NaiveDate::from_ymd(2022, 09, 29);
-
-        assert_eq!(cal.roll_backward(sun), business_thu);
+    /// Returns the `n` business days strictly after the given date, in chronological order. The
+    /// date itself is never included, even if it is a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.next_n_business_days(thu, 2), vec![fri, mon]);
+    /// ```
+    pub fn next_n_business_days(&self, date: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        std::iter::successors(Some(date), |&d| Some(self.next_business_day(d)))
+            .skip(1)
+            .take(n)
+            .collect()
     }
 
-    #[test]
-    fn mon_rolls_backward_same_day() {
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::workweek();
-
-        assert_eq!(cal.roll_backward(mon), mon);
+    /// Returns the `n` business days strictly before the given date, going backwards in time from
+    /// the closest one. The date itself is never included, even if it is a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// assert_eq!(cal.previous_n_business_days(tue, 2), vec![mon, fri]);
+    /// ```
+    pub fn previous_n_business_days(&self, date: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        std::iter::successors(Some(date), |&d| Some(self.previous_business_day(d)))
+            .skip(1)
+            .take(n)
+            .collect()
     }
 
-    #[test]
-    fn sat_next_business_is_tues() {
-        let sat = NaiveDate::from_ymd(2022, 10, 01);
-        let holiday_mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::with_holidays(&[holiday_mon]);
-
-        let business_tue = NaiveDate::from_ymd(2022, 10, 04);
-
-        assert_eq!(cal.next_business_day(sat), business_tue);
+    /// Returns the 1-based index of the business day within its month, or `None` if the date is
+    /// not a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// assert_eq!(cal.business_day_of_month_index(sat), None);
+    /// assert_eq!(cal.business_day_of_month_index(mon), Some(1));
+    /// assert_eq!(cal.business_day_of_month_index(tue), Some(2));
+    /// ```
+    pub fn business_day_of_month_index(&self, date: NaiveDate) -> Option<u32> {
+        if !self.is_business_day(date) {
+            return None;
+        }
+        let first = first_day_of_month(date.year(), date.month());
+        Some(self.count_business_days_inclusive(first, date))
     }
 
-    #[test]
-    fn mon_next_business_is_tues() {
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::with_holidays(&[]);
-
-        let tue = NaiveDate::from_ymd(2022, 10, 04);
-        assert_eq!(cal.next_business_day(mon), tue);
+    /// Returns the [`business_day_of_month_index`](Self::business_day_of_month_index) of each
+    /// date, in the same order, with `None` for dates that are not business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let dates = [1, 3, 4].map(|day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap());
+    /// assert_eq!(cal.business_day_sparse_index(&dates), vec![None, Some(1), Some(2)]);
+    /// ```
+    pub fn business_day_sparse_index(&self, dates: &[NaiveDate]) -> Vec<Option<u32>> {
+        dates
+            .iter()
+            .map(|&date| self.business_day_of_month_index(date))
+            .collect()
     }
 
-    #[test]
-    fn sun_previous_business_is_thu() {
-        let sun = NaiveDate::from_ymd(2022, 10, 02);
-        let holiday_fri = NaiveDate::from_ymd(2022, 09, 30);
-        let cal = Calendar::with_holidays(&[holiday_fri]);
-
-        let business_thu = NaiveDate::from_ymd(2022, 09, 29);
-
-        assert_eq!(cal.previous_business_day(sun), business_thu);
+    /// Returns the 1-based index of the business day within its year, or `None` if the date is
+    /// not a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let jan_2 = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    /// let feb_1 = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+    /// assert_eq!(cal.business_day_of_year_index(jan_2), Some(1));
+    /// assert_eq!(cal.business_day_of_year_index(feb_1), Some(23));
+    /// ```
+    pub fn business_day_of_year_index(&self, date: NaiveDate) -> Option<u32> {
+        if !self.is_business_day(date) {
+            return None;
+        }
+        let first = first_day_of_month(date.year(), 1);
+        Some(self.count_business_days_inclusive(first, date))
     }
 
-    #[test]
-    fn mon_previous_business_is_fri() {
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::with_holidays(&[]);
-
-        let fri = NaiveDate::from_ymd(2022, 09, 30);
-        assert_eq!(cal.previous_business_day(mon), fri);
+    /// Returns the `n`th business day of the given year, counting from 1, or `None` if the year
+    /// has fewer than `n` business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let feb_1 = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+    /// assert_eq!(cal.nth_business_day_of_year(2023, 23), Some(feb_1));
+    /// assert_eq!(cal.nth_business_day_of_year(2023, 0), None);
+    /// assert_eq!(cal.nth_business_day_of_year(2023, 261), None);
+    /// ```
+    pub fn nth_business_day_of_year(&self, year: i32, n: u32) -> Option<NaiveDate> {
+        if n == 0 {
+            return None;
+        }
+        first_day_of_month(year, 1)
+            .iter_days()
+            .take_while(|date| date.year() == year)
+            .filter(|date| self.is_business_day(*date))
+            .nth(n as usize - 1)
     }
 
-    #[test]
-    fn sat_add_2_business_is_thu() {
-        let sat = NaiveDate::from_ymd(2022, 10, 01);
-        let holiday_tues = NaiveDate::from_ymd(2022, 10, 04);
-        let cal = Calendar::with_holidays(&[holiday_tues]);
-
-        let business_thu = NaiveDate::from_ymd(2022, 10, 06);
-
-        assert_eq!(cal.add_business_days(sat, 2), business_thu);
+    /// Returns how far through its year's business days the date is, from `0.0` on the first
+    /// business day to `1.0` on the last, or `None` if the date is not a business day. A year
+    /// with a single business day gives `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let first = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+    /// let last = NaiveDate::from_ymd_opt(2022, 12, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// assert_eq!(cal.business_day_of_year_fraction(first), Some(0.0));
+    /// assert_eq!(cal.business_day_of_year_fraction(last), Some(1.0));
+    /// assert_eq!(cal.business_day_of_year_fraction(sat), None);
+    /// ```
+    pub fn business_day_of_year_fraction(&self, date: NaiveDate) -> Option<f64> {
+        let index = self.business_day_of_year_index(date)?;
+        let total = self.business_days_in_year(date.year());
+        if total == 1 {
+            return Some(0.0);
+        }
+        Some(f64::from(index - 1) / f64::from(total - 1))
     }
 
-    #[test]
-    fn mon_add_2_business_is_wed() {
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
-        let cal = Calendar::workweek();
-
-        let wed = NaiveDate::from_ymd(2022, 10, 05);
-
-        assert_eq!(cal.add_business_days(mon, 2), wed);
+    /// Returns the number of business days remaining in the month after the given business day,
+    /// so the last business day of the month gives `0`. Returns `None` if the date is not a
+    /// business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// assert_eq!(cal.business_day_before_month_end(thu), Some(1));
+    /// assert_eq!(cal.business_day_before_month_end(fri), Some(0));
+    /// ```
+    pub fn business_day_before_month_end(&self, date: NaiveDate) -> Option<u32> {
+        if !self.is_business_day(date) {
+            return None;
+        }
+        let last = last_day_of_month(date.year(), date.month());
+        Some(self.count_business_days_inclusive(date, last) - 1)
     }
 
-    #[test]
-    fn sun_sub_2_business_is_thu() {
-        let sun = NaiveDate::from_ymd(2022, 10, 02);
-        let holiday_fri = NaiveDate::from_ymd(2022, 09, 30);
-        let cal = Calendar::with_holidays(&[holiday_fri]);
+    /// Returns the position of the business day within its month and year, or `None` if the date
+    /// is not a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{BusinessDayPosition, Calendar};
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let position = BusinessDayPosition {
+    ///     index_in_month: 22,
+    ///     index_in_year: 195,
+    ///     index_from_month_end: 0,
+    /// };
+    /// assert_eq!(cal.business_day_position(fri), Some(position));
+    /// ```
+    pub fn business_day_position(&self, date: NaiveDate) -> Option<BusinessDayPosition> {
+        Some(BusinessDayPosition {
+            index_in_month: self.business_day_of_month_index(date)?,
+            index_in_year: self.business_day_of_year_index(date)?,
+            index_from_month_end: self.business_day_before_month_end(date)?,
+        })
+    }
+
+    /// Returns the number of business days in the given year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
+    /// assert_eq!(Calendar::workweek().business_days_in_year(2022), 260);
+    /// assert_eq!(cal.business_days_in_year(2022), 259);
+    /// ```
+    pub fn business_days_in_year(&self, year: i32) -> u32 {
+        self.count_business_days_inclusive(first_day_of_month(year, 1), last_day_of_month(year, 12))
+    }
+
+    /// Counts the days in the given year that fall on a working day of the week, including
+    /// holidays. Subtracting the holidays on working days gives
+    /// [`business_days_in_year`](Self::business_days_in_year).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
+    /// assert_eq!(cal.count_working_weekdays_without_holidays(2022), 260);
+    /// assert_eq!(cal.business_days_in_year(2022), 259);
+    /// ```
+    pub fn count_working_weekdays_without_holidays(&self, year: i32) -> u32 {
+        self.business_day_count_optimistic(
+            first_day_of_month(year, 1),
+            first_day_of_month(year + 1, 1),
+        )
+    }
+
+    /// Returns the number of business days in the given month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// assert_eq!(Calendar::workweek().business_days_in_month(2022, 10), 21);
+    /// ```
+    pub fn business_days_in_month(&self, year: i32, month: u32) -> u32 {
+        self.count_business_days_inclusive(
+            first_day_of_month(year, month),
+            last_day_of_month(year, month),
+        )
+    }
+
+    /// Returns the business days of the given month from the `start_n`th through the `end_n`th,
+    /// counting from 1. If the month has fewer than `end_n` business days, the result stops at
+    /// the last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let days = cal.business_day_within_month_range(2022, 10, 5, 7);
+    /// let ymd = |day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap();
+    /// assert_eq!(days, vec![ymd(7), ymd(10), ymd(11)]);
+    /// assert_eq!(cal.business_day_within_month_range(2022, 10, 21, 30), vec![ymd(31)]);
+    /// ```
+    pub fn business_day_within_month_range(
+        &self,
+        year: i32,
+        month: u32,
+        start_n: u32,
+        end_n: u32,
+    ) -> Vec<NaiveDate> {
+        let skip = start_n.max(1) as usize - 1;
+        let take = (end_n as usize).saturating_sub(skip);
+        self.for_month(year, month)
+            .business_days()
+            .into_iter()
+            .skip(skip)
+            .take(take)
+            .collect()
+    }
+
+    /// Returns `(month, count)` for each month of the given year, from 1 to 12, where `count` is
+    /// the number of business days in the month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let per_month = Calendar::workweek().business_days_per_month(2022);
+    /// assert_eq!(per_month[0], (1, 21));
+    /// assert_eq!(per_month[9], (10, 21));
+    /// ```
+    pub fn business_days_per_month(&self, year: i32) -> [(u32, u32); 12] {
+        let mut per_month = [(0, 0); 12];
+        for (month, entry) in (1..=12).zip(per_month.iter_mut()) {
+            *entry = (month, self.business_days_in_month(year, month));
+        }
+        per_month
+    }
+
+    /// Returns `(quarter, count)` for each quarter of the given year, from 1 to 4, where `count`
+    /// is the number of business days in the quarter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let per_quarter = Calendar::workweek().business_days_per_quarter(2022);
+    /// assert_eq!(per_quarter, [(1, 64), (2, 65), (3, 66), (4, 65)]);
+    /// ```
+    pub fn business_days_per_quarter(&self, year: i32) -> [(u32, u32); 4] {
+        let mut per_quarter = [(1, 0), (2, 0), (3, 0), (4, 0)];
+        for (month, count) in self.business_days_per_month(year) {
+            per_quarter[(month as usize - 1) / 3].1 += count;
+        }
+        per_quarter
+    }
+
+    /// Returns the business days of the given year, grouped by month from 1 to 12. Every month
+    /// is present, with its business days in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let by_month = Calendar::workweek().business_day_list_by_month(2022);
+    /// assert_eq!(by_month.len(), 12);
+    /// assert_eq!(by_month[&10].len(), 21);
+    /// ```
+    pub fn business_day_list_by_month(&self, year: i32) -> HashMap<u32, Vec<NaiveDate>> {
+        self.days_by_month(year, true)
+    }
+
+    /// Returns the business days of the given year, grouped by ISO year and week number. Every
+    /// week that any day of the year falls in is present, with its business days in chronological
+    /// order, so a week without business days has an empty list.
+    ///
+    /// Days at the start of January can belong to the previous ISO year's last week, and days at
+    /// the end of December to week 1 of the next ISO year, so they are keyed by that ISO year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let by_week = cal.business_day_iso_week_map(2021);
+    /// // Friday the 1st of January 2021 is in week 53 of 2020
+    /// assert_eq!(by_week.len(), 53);
+    /// assert_eq!(by_week[&(2020, 53)], vec![NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()]);
+    /// assert_eq!(by_week[&(2021, 1)].len(), 5);
+    /// ```
+    pub fn business_day_iso_week_map(&self, year: i32) -> HashMap<(i32, u32), Vec<NaiveDate>> {
+        let mut by_week: HashMap<_, Vec<_>> = HashMap::new();
+        for date in first_day_of_month(year, 1)
+            .iter_days()
+            .take_while(|date| date.year() == year)
+        {
+            let iso_week = date.iso_week();
+            let week = by_week
+                .entry((iso_week.year(), iso_week.week()))
+                .or_default();
+            if self.is_business_day(date) {
+                week.push(date);
+            }
+        }
+        by_week
+    }
+
+    /// Returns the weekends and holidays of the given year, grouped by month from 1 to 12. Every
+    /// month is present, with its non-business days in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let by_month = Calendar::workweek().non_business_day_list_by_month(2022);
+    /// assert_eq!(by_month.len(), 12);
+    /// assert_eq!(by_month[&10].len(), 10);
+    /// ```
+    pub fn non_business_day_list_by_month(&self, year: i32) -> HashMap<u32, Vec<NaiveDate>> {
+        self.days_by_month(year, false)
+    }
+
+    /// Returns the average number of business days per year across the given range of years.
+    /// Returns NaN if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// assert_eq!(cal.estimate_business_days_per_year(2022..=2023), 260.0);
+    /// assert_eq!(cal.estimate_business_days_per_year(2023..=2024), 261.0);
+    /// ```
+    pub fn estimate_business_days_per_year(&self, years: RangeInclusive<i32>) -> f64 {
+        let (mut total, mut count) = (0, 0);
+        for year in years {
+            total += self.business_days_in_year(year);
+            count += 1;
+        }
+        total as f64 / count as f64
+    }
+
+    /// Returns a map from every date in `[start, end]` to whether it is a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let map = cal.to_day_map(fri, sat);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&fri], true);
+    /// assert_eq!(map[&sat], false);
+    /// ```
+    pub fn to_day_map(&self, start: NaiveDate, end: NaiveDate) -> HashMap<NaiveDate, bool> {
+        let days = (end - start).num_days() + 1;
+        let mut map = HashMap::with_capacity(days.max(0) as usize);
+        let mut date = start;
+        while date <= end {
+            map.insert(date, self.is_business_day(date));
+            date += Duration::days(1);
+        }
+        map
+    }
+
+    /// Returns a map from every date in `[start, end]` to whether it is a business day. This is
+    /// the same as [`to_day_map`](Self::to_day_map), under the name used by data frame
+    /// integrations.
+    pub fn business_day_hash_map_for_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> HashMap<NaiveDate, bool> {
+        self.to_day_map(start, end)
+    }
+
+    /// Returns the number of calendar days until the next business day, or `0` if the date is
+    /// already a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.days_until_business_day(sat), 2);
+    /// assert_eq!(cal.days_until_business_day(mon), 0);
+    /// ```
+    pub fn days_until_business_day(&self, date: NaiveDate) -> u32 {
+        (self.roll_forward(date) - date).num_days() as u32
+    }
+
+    /// Returns the number of calendar days since the previous business day, or `0` if the date is
+    /// already a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// assert_eq!(cal.days_since_business_day(sun), 2);
+    /// assert_eq!(cal.days_since_business_day(fri), 0);
+    /// ```
+    pub fn days_since_business_day(&self, date: NaiveDate) -> u32 {
+        (date - self.roll_backward(date)).num_days() as u32
+    }
+
+    /// Counts business days in the `window` calendar days ending on the given date, i.e. in
+    /// `(date - window, date]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.rolling_business_day_count(sun, 7), 5);
+    /// assert_eq!(cal.rolling_business_day_count(mon, 2), 1);
+    /// ```
+    pub fn rolling_business_day_count(&self, date: NaiveDate, window: u32) -> u32 {
+        let start = date - Duration::days(i64::from(window)) + Duration::days(1);
+        self.count_business_days_inclusive(start, date)
+    }
+
+    /// Counts the ISO weeks with at least one business day in `[start, end]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+    /// assert_eq!(cal.business_week_count(sat, mon), 2);
+    /// ```
+    pub fn business_week_count(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        let mut weeks = HashSet::new();
+        let mut date = start;
+        while date <= end {
+            if self.is_business_day(date) {
+                weeks.insert(week_start(date));
+            }
+            date += Duration::days(1);
+        }
+        weeks.len() as u32
+    }
+
+    /// Counts the ISO weeks with a business day in `[start, end]` in which every working day is a
+    /// business day, i.e. weeks without any holidays on working days. The whole week is checked,
+    /// even the days falling outside the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 3).unwrap()]);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+    /// // The week of the 3rd has a holiday, and the week before has no business days in range
+    /// assert_eq!(cal.full_business_week_count(sat, mon), 1);
+    /// ```
+    pub fn full_business_week_count(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        let mut count = 0;
+        if start > end {
+            return count;
+        }
+        let mut monday = week_start(start);
+        while monday <= end {
+            let mut working_days = (0..7)
+                .map(|offset| monday + Duration::days(offset))
+                .filter(|date| self.working_days.contains(&date.weekday()));
+            let has_business_day_in_range = working_days
+                .clone()
+                .any(|date| start <= date && date <= end && self.is_business_day(date));
+            let is_full = working_days.all(|date| !self.holidays.contains(&date));
+            if has_business_day_in_range && is_full {
+                count += 1;
+            }
+            monday += Duration::weeks(1);
+        }
+        count
+    }
+
+    /// Returns the last business day of the given month, or `None` if the month has no business
+    /// days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the month is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let last = NaiveDate::from_ymd_opt(2022, 7, 29).unwrap();
+    /// assert_eq!(cal.last_business_day_of_month(2022, 7), Some(last));
+    /// ```
+    pub fn last_business_day_of_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        let first = first_day_of_month(year, month);
+        let mut date = last_day_of_month(year, month);
+        while date >= first {
+            if self.is_business_day(date) {
+                return Some(date);
+            }
+            date -= Duration::days(1);
+        }
+        None
+    }
+
+    /// Returns the date `n` business days before the last business day of the month containing
+    /// `date`, so `0` gives the last business day itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the month has no business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let date = NaiveDate::from_ymd_opt(2022, 7, 12).unwrap();
+    /// let last = NaiveDate::from_ymd_opt(2022, 7, 29).unwrap();
+    /// let deadline = NaiveDate::from_ymd_opt(2022, 7, 27).unwrap();
+    /// assert_eq!(cal.business_days_before_month_end_target(date, 0), last);
+    /// assert_eq!(cal.business_days_before_month_end_target(date, 2), deadline);
+    /// ```
+    pub fn business_days_before_month_end_target(&self, date: NaiveDate, n: u32) -> NaiveDate {
+        let last = self
+            .last_business_day_of_month(date.year(), date.month())
+            .expect("month has no business days");
+        self.subtract_business_days(last, u64::from(n))
+    }
+
+    /// Returns `true` if the date is the second-to-last business day of its month.
+    ///
+    /// A month needs at least two business days to have a penultimate one, so this always
+    /// returns `false` for months with fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// assert_eq!(cal.is_penultimate_business_day_of_month(thu), true);
+    /// assert_eq!(cal.is_penultimate_business_day_of_month(fri), false);
+    /// ```
+    pub fn is_penultimate_business_day_of_month(&self, date: NaiveDate) -> bool {
+        if !self.is_business_day(date) {
+            return false;
+        }
+        match self.last_business_day_of_month(date.year(), date.month()) {
+            Some(last) => last != date && self.next_business_day(date) == last,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the date is the last business day of a fiscal year starting in the given
+    /// month, e.g. `4` for the UK's April to March tax year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fiscal_year_start_month` is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2023, 4, 3).unwrap();
+    /// assert_eq!(cal.is_fiscal_year_end(fri, 4), true);
+    /// assert_eq!(cal.is_fiscal_year_start(mon, 4), true);
+    /// assert_eq!(cal.is_fiscal_year_end(fri, 1), false);
+    /// ```
+    pub fn is_fiscal_year_end(&self, date: NaiveDate, fiscal_year_start_month: u32) -> bool {
+        assert!(
+            (1..=12).contains(&fiscal_year_start_month),
+            "month must be between 1 and 12"
+        );
+        let last_month = (fiscal_year_start_month + 10) % 12 + 1;
+        date.month() == last_month
+            && self.last_business_day_of_month(date.year(), date.month()) == Some(date)
+    }
+
+    /// Returns `true` if the date is the first business day of a fiscal year starting in the given
+    /// month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fiscal_year_start_month` is not between 1 and 12.
+    pub fn is_fiscal_year_start(&self, date: NaiveDate, fiscal_year_start_month: u32) -> bool {
+        assert!(
+            (1..=12).contains(&fiscal_year_start_month),
+            "month must be between 1 and 12"
+        );
+        date.month() == fiscal_year_start_month && self.business_day_of_month_index(date) == Some(1)
+    }
+
+    /// Groups dates by the ISO week they fall in, keyed by `(iso_year, iso_week)`. Dates within
+    /// each week are sorted. Non-business days are grouped the same way as business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let weeks = cal.group_by_business_week(&[mon, sun, fri]);
+    /// assert_eq!(weeks[&(2022, 39)], vec![fri, sun]);
+    /// assert_eq!(weeks[&(2022, 40)], vec![mon]);
+    /// ```
+    pub fn group_by_business_week(
+        &self,
+        dates: &[NaiveDate],
+    ) -> BTreeMap<(i32, u32), Vec<NaiveDate>> {
+        let mut weeks: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for &date in dates {
+            let week = date.iso_week();
+            weeks
+                .entry((week.year(), week.week()))
+                .or_default()
+                .push(date);
+        }
+        for dates in weeks.values_mut() {
+            dates.sort();
+        }
+        weeks
+    }
+
+    /// Returns the date `t_plus` business days after the trade date, e.g. `T+2` for a typical
+    /// bond settlement. Unlike [`Calendar::add_business_days`], the trade date must itself be a
+    /// business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::NotABusinessDay`] if the trade date is not a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, CalendarError};
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.effective_date(thu, 2), Ok(mon));
+    /// assert_eq!(cal.effective_date(sat, 2), Err(CalendarError::NotABusinessDay(sat)));
+    /// ```
+    pub fn effective_date(
+        &self,
+        trade_date: NaiveDate,
+        t_plus: u32,
+    ) -> Result<NaiveDate, CalendarError> {
+        if !self.is_business_day(trade_date) {
+            return Err(CalendarError::NotABusinessDay(trade_date));
+        }
+        Ok(self.add_business_days(trade_date, u64::from(t_plus)))
+    }
+
+    /// Adjusts the date to a business day according to the given convention. Business days are
+    /// always returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollingConvention};
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 7, 31).unwrap();
+    /// let fri_before = NaiveDate::from_ymd_opt(2022, 7, 29).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.roll_with_convention(sat, RollingConvention::Following), mon);
+    /// assert_eq!(cal.roll_with_convention(sun, RollingConvention::ModifiedFollowing), fri_before);
+    /// assert_eq!(cal.roll_with_convention(sat, RollingConvention::Preceding), fri);
+    /// assert_eq!(cal.roll_with_convention(sat, RollingConvention::ModifiedPreceding), mon);
+    /// ```
+    pub fn roll_with_convention(
+        &self,
+        date: NaiveDate,
+        convention: RollingConvention,
+    ) -> NaiveDate {
+        match convention {
+            RollingConvention::Unadjusted => date,
+            RollingConvention::Following => self.roll_forward(date),
+            RollingConvention::ModifiedFollowing => {
+                let rolled = self.roll_forward(date);
+                if rolled.month() == date.month() {
+                    rolled
+                } else {
+                    self.roll_backward(date)
+                }
+            }
+            RollingConvention::Preceding => self.roll_backward(date),
+            RollingConvention::ModifiedPreceding => {
+                let rolled = self.roll_backward(date);
+                if rolled.month() == date.month() {
+                    rolled
+                } else {
+                    self.roll_forward(date)
+                }
+            }
+        }
+    }
+
+    /// Adds a duration to the date and rolls the result forward to a business day, e.g. for a
+    /// deadline 30 days from now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Duration, NaiveDate};
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let date = NaiveDate::from_ymd_opt(2022, 9, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.business_day_aware_add_duration(date, Duration::days(30)), mon);
+    /// ```
+    pub fn business_day_aware_add_duration(
+        &self,
+        date: NaiveDate,
+        duration: Duration,
+    ) -> NaiveDate {
+        self.business_day_aware_add_duration_with_convention(
+            date,
+            duration,
+            RollingConvention::Following,
+        )
+    }
+
+    /// Adds a duration to the date and adjusts the result with
+    /// [`roll_with_convention`](Self::roll_with_convention).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Duration, NaiveDate};
+    /// # use business::{Calendar, RollingConvention};
+    /// let cal = Calendar::workweek();
+    /// // Sunday the 31st of July rolls back into the month
+    /// let date = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 7, 29).unwrap();
+    /// assert_eq!(
+    ///     cal.business_day_aware_add_duration_with_convention(
+    ///         date,
+    ///         Duration::days(30),
+    ///         RollingConvention::ModifiedFollowing,
+    ///     ),
+    ///     fri
+    /// );
+    /// ```
+    pub fn business_day_aware_add_duration_with_convention(
+        &self,
+        date: NaiveDate,
+        duration: Duration,
+        convention: RollingConvention,
+    ) -> NaiveDate {
+        self.roll_with_convention(date + duration, convention)
+    }
+
+    /// Adjusts each date with [`roll_with_convention`](Self::roll_with_convention), keeping the
+    /// input order. Unless `preserve_duplicates` is set, dates that roll onto one already in the
+    /// result are dropped, so the result can be shorter than the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollingConvention};
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let following = RollingConvention::Following;
+    /// assert_eq!(cal.align_to_business_day_grid(&[sat, sun], following, false), vec![mon]);
+    /// assert_eq!(
+    ///     cal.align_to_business_day_grid(&[sat, sun], following, true),
+    ///     vec![mon, mon]
+    /// );
+    /// ```
+    pub fn align_to_business_day_grid(
+        &self,
+        dates: &[NaiveDate],
+        convention: RollingConvention,
+        preserve_duplicates: bool,
+    ) -> Vec<NaiveDate> {
+        let mut seen = HashSet::new();
+        dates
+            .iter()
+            .map(|&date| self.roll_with_convention(date, convention))
+            .filter(|&date| preserve_duplicates || seen.insert(date))
+            .collect()
+    }
+
+    /// Returns the maturity date of a term starting on the given date.
+    ///
+    /// A [`Tenor::Days`] term counts business days, as with [`Calendar::add_business_days`].
+    /// The other tenors count calendar weeks, months or years, and the resulting date is then
+    /// adjusted with the given convention. Adding months to a day that does not exist in the
+    /// target month, such as the 31st, gives the last day of that month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RollingConvention, Tenor};
+    /// let cal = Calendar::workweek();
+    /// let start = NaiveDate::from_ymd_opt(2022, 6, 30).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+    /// let following = RollingConvention::Following;
+    /// assert_eq!(cal.maturity_date(start, Tenor::Days(1), following), fri);
+    /// assert_eq!(cal.maturity_date(start, Tenor::Months(4), following), mon);
+    /// ```
+    pub fn maturity_date(
+        &self,
+        start: NaiveDate,
+        tenor: Tenor,
+        convention: RollingConvention,
+    ) -> NaiveDate {
+        let unadjusted = match tenor {
+            Tenor::Days(days) => return self.add_business_days(start, u64::from(days)),
+            Tenor::Weeks(weeks) => start + Duration::weeks(i64::from(weeks)),
+            Tenor::Months(months) => add_months(start, months),
+            Tenor::Years(years) => add_months(start, years * 12),
+        };
+        self.roll_with_convention(unadjusted, convention)
+    }
+
+    /// Returns the first anniversary of `start`, adjusted to a business day with
+    /// [`RollingConvention::ModifiedFollowing`], that falls after `from`. Anniversaries of February 29th fall on
+    /// February 28th outside leap years. `start` itself is not an anniversary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let start = NaiveDate::from_ymd_opt(2019, 10, 1).unwrap();
+    /// let from = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    /// // 1st October 2022 is a Saturday
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.next_business_anniversary(start, from), mon);
+    /// ```
+    pub fn next_business_anniversary(&self, start: NaiveDate, from: NaiveDate) -> NaiveDate {
+        let mut years = 1;
+        loop {
+            let anniversary = self.roll_with_convention(
+                add_months(start, 12 * years),
+                RollingConvention::ModifiedFollowing,
+            );
+            if anniversary > from {
+                return anniversary;
+            }
+            years += 1;
+        }
+    }
+
+    /// Returns the last business day of the month before the given date's month, i.e. the last
+    /// day of the previous month rolled backward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let aug_1 = NaiveDate::from_ymd_opt(2022, 8, 1).unwrap();
+    /// let jul_29 = NaiveDate::from_ymd_opt(2022, 7, 29).unwrap();
+    /// assert_eq!(cal.previous_month_end_business_day(aug_1), jul_29);
+    /// ```
+    pub fn previous_month_end_business_day(&self, date: NaiveDate) -> NaiveDate {
+        let previous_month_end = first_day_of_month(date.year(), date.month()) - Duration::days(1);
+        self.roll_backward(previous_month_end)
+    }
+
+    /// Returns the business day status of every day of the given year, indexed by
+    /// [`Datelike::ordinal0`]. The last entry is always `false` outside leap years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Datelike, NaiveDate};
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let map = cal.business_day_map(2022);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(map[sat.ordinal0() as usize], false);
+    /// assert_eq!(map[mon.ordinal0() as usize], true);
+    /// assert_eq!(map[365], false);
+    /// ```
+    pub fn business_day_map(&self, year: i32) -> [bool; 366] {
+        let mut map = [false; 366];
+        let mut date = first_day_of_month(year, 1);
+        while date.year() == year {
+            map[date.ordinal0() as usize] = self.is_business_day(date);
+            date += Duration::days(1);
+        }
+        map
+    }
+
+    /// Returns whether each date is a business day, in the same order. For years with at least
+    /// as many dates as days, the year's [`business_day_map`](Self::business_day_map) is built
+    /// once and the dates are read from it; dates in other years are checked one by one. This
+    /// never needs more holiday lookups than checking every date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let dates = [30, 1, 3].map(|day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap());
+    /// assert_eq!(cal.is_business_day_batch(&dates), vec![false, false, true]);
+    /// ```
+    pub fn is_business_day_batch(&self, dates: &[NaiveDate]) -> Vec<bool> {
+        let mut dates_per_year: HashMap<i32, usize> = HashMap::new();
+        for date in dates {
+            *dates_per_year.entry(date.year()).or_default() += 1;
+        }
+        let maps: HashMap<i32, [bool; 366]> = dates_per_year
+            .into_iter()
+            .filter(|&(year, count)| count >= last_day_of_month(year, 12).ordinal() as usize)
+            .map(|(year, _)| (year, self.business_day_map(year)))
+            .collect();
+
+        dates
+            .iter()
+            .map(|&date| match maps.get(&date.year()) {
+                Some(map) => map[date.ordinal0() as usize],
+                None => self.is_business_day(date),
+            })
+            .collect()
+    }
+
+    /// Returns the days of the given year as two columns, the day of the year from `1` and
+    /// whether that day is a business day, for building a data frame series or Arrow array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let (ordinals, is_business) = cal.to_polars_series(2022);
+    /// assert_eq!(ordinals.len(), 365);
+    /// assert_eq!(ordinals[..3], [1, 2, 3]);
+    /// // Saturday, Sunday, Monday
+    /// assert_eq!(is_business[..3], [false, false, true]);
+    /// ```
+    pub fn to_polars_series(&self, year: i32) -> (Vec<i32>, Vec<bool>) {
+        let first = first_day_of_month(year, 1);
+        first
+            .iter_days()
+            .take_while(|date| date.year() == year)
+            .map(|date| (date.ordinal() as i32, self.is_business_day(date)))
+            .unzip()
+    }
+
+    /// Returns the running count of business days in the given year, where entry `i` is the
+    /// number of business days among the first `i` days of the year. Entry `0` is always `0`,
+    /// and outside leap years the last entry repeats the one before it.
+    ///
+    /// This makes counting the business days in `[start, end]` within the year a subtraction:
+    /// `prefix[end.ordinal()] - prefix[start.ordinal0()]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Datelike, NaiveDate};
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let prefix = cal.business_day_count_prefix_sum(2022);
+    /// let start = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+    /// assert_eq!(prefix[end.ordinal() as usize] - prefix[start.ordinal0() as usize], 21);
+    /// assert_eq!(prefix[366], 260);
+    /// ```
+    pub fn business_day_count_prefix_sum(&self, year: i32) -> [u32; 367] {
+        let map = self.business_day_map(year);
+        let mut prefix = [0; 367];
+        for (i, &is_business) in map.iter().enumerate() {
+            prefix[i + 1] = prefix[i] + u32::from(is_business);
+        }
+        prefix
+    }
+
+    /// Returns the signed number of calendar days to the nearest business day: `0` for a
+    /// business day, positive if the nearest one is in the future and negative if it is in the
+    /// past. When the previous and next business days are equally far away, the future one wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.business_day_skew(sat), -1);
+    /// assert_eq!(cal.business_day_skew(sun), 1);
+    /// assert_eq!(cal.business_day_skew(mon), 0);
+    /// ```
+    pub fn business_day_skew(&self, date: NaiveDate) -> i32 {
+        let forward = self.days_until_business_day(date);
+        let backward = self.days_since_business_day(date);
+        if forward <= backward {
+            forward as i32
+        } else {
+            -(backward as i32)
+        }
+    }
+
+    /// Counts business days in `[start, end)`, leaving out any dates in `exclude`. This allows
+    /// applying one-off closures without building a modified calendar. Duplicates in `exclude`
+    /// are only counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// let next_mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+    /// assert_eq!(cal.count_business_days_in_range_excluding(mon, next_mon, &[tue]), 4);
+    /// ```
+    pub fn count_business_days_in_range_excluding(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        exclude: &[NaiveDate],
+    ) -> u32 {
+        let exclude: HashSet<_> = exclude.iter().collect();
+        let mut count = 0;
+        let mut date = start;
+        while date < end {
+            if self.is_business_day(date) && !exclude.contains(&date) {
+                count += 1;
+            }
+            date += Duration::days(1);
+        }
+        count
+    }
+
+    /// Returns all holidays in chronological order, along with their names if they have one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 26).unwrap();
+    /// let mut cal = Calendar::with_holidays(&[boxing_day, xmas]);
+    /// cal.add_holiday_name(xmas, "Christmas Day");
+    ///
+    /// assert_eq!(
+    ///     cal.all_holidays_as_named(),
+    ///     vec![(xmas, Some("Christmas Day".to_string())), (boxing_day, None)],
+    /// );
+    /// ```
+    pub fn all_holidays_as_named(&self) -> Vec<(NaiveDate, Option<String>)> {
+        let mut holidays: Vec<_> = self
+            .holidays
+            .iter()
+            .map(|&date| (date, self.holiday_name(date).map(str::to_string)))
+            .collect();
+        holidays.sort_unstable();
+        holidays
+    }
+
+    /// Returns the first name of the holiday on the given date, or `None` if the date is not a
+    /// holiday or has no name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let mut cal = Calendar::with_holidays(&[xmas]);
+    /// cal.add_holiday_name(xmas, "Christmas Day");
+    /// assert_eq!(cal.holiday_name(xmas), Some("Christmas Day"));
+    /// ```
+    pub fn holiday_name(&self, date: NaiveDate) -> Option<&str> {
+        self.holiday_names_on(date).first().copied()
+    }
+
+    /// Names the holiday on the given date, after any names it already has. Adding a name the
+    /// date already has does nothing, and so does naming a date that is not in `holidays`.
+    pub fn add_holiday_name(&mut self, date: NaiveDate, name: &str) {
+        if !self.holidays.contains(&date) {
+            return;
+        }
+        let names = self.holiday_names.entry(date).or_default();
+        if !names.iter().any(|other| other == name) {
+            names.push(name.to_string());
+        }
+    }
+
+    /// Returns every name of the holiday on the given date, first name first. Returns an empty
+    /// list if the date is not a holiday or has no name.
+    pub fn holiday_names_on(&self, date: NaiveDate) -> Vec<&str> {
+        if !self.holidays.contains(&date) {
+            return Vec::new();
+        }
+        self.holiday_names
+            .get(&date)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns `true` if the date is a holiday with more than one name, e.g. a religious and a
+    /// national holiday falling on the same day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let date = NaiveDate::from_ymd_opt(2023, 4, 21).unwrap();
+    /// let mut cal = Calendar::with_holidays(&[date]);
+    /// cal.add_holiday_name(date, "Eid al-Fitr");
+    /// assert_eq!(cal.is_double_holiday(date), false);
+    ///
+    /// cal.add_holiday_name(date, "Kartini Day");
+    /// assert_eq!(cal.is_double_holiday(date), true);
+    /// assert_eq!(cal.holiday_names_on(date), vec!["Eid al-Fitr", "Kartini Day"]);
+    /// ```
+    pub fn is_double_holiday(&self, date: NaiveDate) -> bool {
+        self.holiday_names_on(date).len() > 1
+    }
+
+    /// Returns the signed number of business days from the reference date to the given date, or
+    /// `None` if the date is not a business day.
+    ///
+    /// Business days after the reference are numbered `1, 2, ...` and those before it
+    /// `-1, -2, ...`, so consecutive business days always have consecutive numbers. The reference
+    /// itself is `0`, and does not need to be a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.business_day_sequence_number(mon, fri), Some(1));
+    /// assert_eq!(cal.business_day_sequence_number(fri, mon), Some(-1));
+    /// assert_eq!(cal.business_day_sequence_number(mon, sat), Some(1));
+    /// assert_eq!(cal.business_day_sequence_number(sat, fri), None);
+    /// ```
+    pub fn business_day_sequence_number(
+        &self,
+        date: NaiveDate,
+        reference: NaiveDate,
+    ) -> Option<i64> {
+        if !self.is_business_day(date) {
+            return None;
+        }
+        let one_day = Duration::days(1);
+        let number = if date >= reference {
+            i64::from(self.count_business_days_inclusive(reference + one_day, date))
+        } else {
+            -i64::from(self.count_business_days_inclusive(date, reference - one_day))
+        };
+        Some(number)
+    }
+
+    /// Returns the offset of the date from the reference in business days, or `None` if the date
+    /// is not a business day. This is the same numbering as
+    /// [`business_day_sequence_number`](Self::business_day_sequence_number), for indexing
+    /// business day series from a fixed reference date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let reference = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 10, 6).unwrap();
+    ///
+    /// assert_eq!(cal.business_day_epoch_offset(thu, reference), Some(4));
+    /// assert_eq!(cal.date_at_epoch_offset(reference, 4), thu);
+    /// ```
+    pub fn business_day_epoch_offset(&self, date: NaiveDate, reference: NaiveDate) -> Option<i64> {
+        self.business_day_sequence_number(date, reference)
+    }
+
+    /// Returns the business day at the given offset from the reference, the inverse of
+    /// [`business_day_epoch_offset`](Self::business_day_epoch_offset). Positive offsets count
+    /// business days after the reference and negative ones count business days before it. An
+    /// offset of `0` returns the reference itself.
+    pub fn date_at_epoch_offset(&self, reference: NaiveDate, offset: i64) -> NaiveDate {
+        let mut date = reference;
+        for _ in 0..offset.unsigned_abs() {
+            date = if offset > 0 {
+                self.next_business_day(date)
+            } else {
+                self.previous_business_day(date)
+            };
+        }
+        date
+    }
+
+    /// Returns the date in the previous month that falls on the same weekday as the given date
+    /// and is closest to the same day of the month.
+    ///
+    /// If the previous month is too short to have the same day of the month, its last day is used
+    /// as the target instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 17).unwrap();
+    /// let mon_before = NaiveDate::from_ymd_opt(2022, 9, 19).unwrap();
+    /// assert_eq!(cal.prev_month_same_weekday(mon), mon_before);
+    /// ```
+    pub fn prev_month_same_weekday(&self, date: NaiveDate) -> NaiveDate {
+        let prev_month_end = first_day_of_month(date.year(), date.month()) - Duration::days(1);
+        let prev_month_start = first_day_of_month(prev_month_end.year(), prev_month_end.month());
+        let target = prev_month_end
+            .with_day(date.day().min(prev_month_end.day()))
+            .unwrap();
+
+        let days_ahead = (i64::from(date.weekday().num_days_from_monday())
+            - i64::from(target.weekday().num_days_from_monday()))
+        .rem_euclid(7);
+        let closest = if days_ahead <= 3 {
+            target + Duration::days(days_ahead)
+        } else {
+            target - Duration::days(7 - days_ahead)
+        };
+
+        if closest < prev_month_start {
+            closest + Duration::weeks(1)
+        } else if closest > prev_month_end {
+            closest - Duration::weeks(1)
+        } else {
+            closest
+        }
+    }
+
+    /// Returns the business day in the previous month with the same index within its month as
+    /// the given date, e.g. the 3rd business day of September for the 3rd business day of
+    /// October. If the previous month has fewer business days, its last business day is returned.
+    ///
+    /// Returns `None` if the date is not a business day, or the previous month has no business
+    /// days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let oct_5 = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+    /// let sep_5 = NaiveDate::from_ymd_opt(2022, 9, 5).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// assert_eq!(cal.prev_month_same_business_position(oct_5), Some(sep_5));
+    /// assert_eq!(cal.prev_month_same_business_position(sat), None);
+    /// ```
+    pub fn prev_month_same_business_position(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let index = self.business_day_of_month_index(date)? as usize;
+        let prev_month_end = first_day_of_month(date.year(), date.month()) - Duration::days(1);
+        let business_days = self
+            .for_month(prev_month_end.year(), prev_month_end.month())
+            .business_days();
+        business_days
+            .get(index - 1)
+            .or_else(|| business_days.last())
+            .copied()
+    }
+
+    /// Counts the distinct business days across all of the given lists of dates. Dates that
+    /// appear in several lists, or several times in one list, are only counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.count_unique_business_days_across(&[&[fri, sat], &[fri, mon]]), 2);
+    /// ```
+    pub fn count_unique_business_days_across(&self, date_lists: &[&[NaiveDate]]) -> u32 {
+        let unique: HashSet<_> = date_lists
+            .iter()
+            .flat_map(|dates| dates.iter())
+            .filter(|&&date| self.is_business_day(date))
+            .collect();
+        unique.len() as u32
+    }
+
+    /// Returns the last business day before the given holiday, or `None` if the date is not one
+    /// of the calendar's holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2020, 12, 24).unwrap();
+    /// let cal = Calendar::with_holidays(&[xmas]);
+    /// assert_eq!(cal.business_day_before_holiday(xmas), Some(xmas_eve));
+    /// assert_eq!(cal.business_day_before_holiday(xmas_eve), None);
+    /// ```
+    pub fn business_day_before_holiday(&self, date: NaiveDate) -> Option<NaiveDate> {
+        if !self.holidays.contains(&date) {
+            return None;
+        }
+        Some(self.previous_business_day(date))
+    }
+
+    /// Returns the first business day after the given holiday, or `None` if the date is not one
+    /// of the calendar's holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+    /// let cal = Calendar::with_holidays(&[xmas]);
+    /// assert_eq!(cal.next_business_day_after_holiday(xmas), Some(mon));
+    /// assert_eq!(cal.next_business_day_after_holiday(mon), None);
+    /// ```
+    pub fn next_business_day_after_holiday(&self, date: NaiveDate) -> Option<NaiveDate> {
+        if !self.holidays.contains(&date) {
+            return None;
+        }
+        Some(self.next_business_day(date))
+    }
+
+    /// Returns up to `count` holidays strictly after `from`, in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 26).unwrap();
+    /// let new_year = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let cal = Calendar::with_holidays(&[new_year, boxing_day, xmas]);
+    ///
+    /// assert_eq!(cal.upcoming_holidays(xmas, 5), vec![boxing_day, new_year]);
+    /// assert_eq!(cal.past_holidays(new_year, 1), vec![boxing_day]);
+    /// ```
+    pub fn upcoming_holidays(&self, from: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        let mut holidays: Vec<_> = self
+            .holidays
+            .iter()
+            .filter(|&&date| date > from)
+            .cloned()
+            .collect();
+        if count < holidays.len() {
+            holidays.select_nth_unstable(count);
+            holidays.truncate(count);
+        }
+        holidays.sort_unstable();
+        holidays
+    }
+
+    /// Returns up to `count` holidays strictly before `from`, most recent first.
+    pub fn past_holidays(&self, from: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        let mut holidays: Vec<_> = self
+            .holidays
+            .iter()
+            .filter(|&&date| date < from)
+            .cloned()
+            .collect();
+        if count < holidays.len() {
+            holidays.select_nth_unstable_by(count, |a, b| b.cmp(a));
+            holidays.truncate(count);
+        }
+        holidays.sort_unstable_by(|a, b| b.cmp(a));
+        holidays
+    }
+
+    /// Counts business days in `[start, end)`. Returns `0` if `end` is not after `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// assert_eq!(cal.business_days_between(fri, tue), 2);
+    /// assert_eq!(cal.business_days_between(fri, mon), 1);
+    /// assert_eq!(cal.business_days_between(tue, fri), 0);
+    /// ```
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        self.count_business_days_inclusive(start, end - Duration::days(1))
+    }
+
+    /// Counts the days in `[start, end)` that fall on a working day of the week, ignoring
+    /// holidays. This takes constant time, and is an upper bound for
+    /// [`business_days_between`](Self::business_days_between). Returns `0` if `end` is not after
+    /// `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let cal = Calendar::with_holidays(&[xmas]);
+    /// let start = NaiveDate::from_ymd_opt(2020, 12, 21).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// assert_eq!(cal.business_day_count_optimistic(start, end), 9);
+    /// assert_eq!(cal.business_days_between(start, end), 8);
+    /// ```
+    pub fn business_day_count_optimistic(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        if end <= start {
+            return 0;
+        }
+        let days = (end - start).num_days();
+        let full_weeks = (days / 7) as u32;
+        let remainder = (0..days % 7)
+            .filter(|&offset| {
+                let date = start + Duration::days(offset);
+                self.working_days.contains(&date.weekday())
+            })
+            .count() as u32;
+        full_weeks * self.working_days.len() as u32 + remainder
+    }
+
+    /// Returns the number of business days left from `from` until `target`, counting `from` if it
+    /// is a business day but not `target`. Returns `None` if `target` is not after `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+    /// assert_eq!(cal.business_days_remaining_until(wed, sat), Some(2));
+    /// assert_eq!(cal.business_days_remaining_until(sat, wed), None);
+    /// ```
+    pub fn business_days_remaining_until(&self, target: NaiveDate, from: NaiveDate) -> Option<u32> {
+        if target <= from {
+            return None;
+        }
+        Some(self.business_days_between(from, target))
+    }
+
+    /// Returns each date in `[target - max_days, target]`, in chronological order, with the
+    /// number of business days left until `target` as counted by
+    /// [`business_days_remaining_until`](Self::business_days_remaining_until). Non-business days
+    /// have the same count as the business day after them, and `target` itself has `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+    /// let countdown = cal.business_day_countdown_vec(tue, 4);
+    ///
+    /// let counts: Vec<_> = countdown.iter().map(|&(_, count)| count).collect();
+    /// // Friday to Tuesday
+    /// assert_eq!(counts, vec![2, 1, 1, 1, 0]);
+    /// ```
+    pub fn business_day_countdown_vec(
+        &self,
+        target: NaiveDate,
+        max_days: u32,
+    ) -> Vec<(NaiveDate, u32)> {
+        let mut countdown = Vec::with_capacity(max_days as usize + 1);
+        countdown.push((target, 0));
+        let mut remaining = 0;
+        let mut date = target;
+        for _ in 0..max_days {
+            date -= Duration::days(1);
+            if self.is_business_day(date) {
+                remaining += 1;
+            }
+            countdown.push((date, remaining));
+        }
+        countdown.reverse();
+        countdown
+    }
+
+    /// Returns the number of business days left from today, in the local timezone, until
+    /// `target`. See [`Calendar::business_days_remaining_until`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let far_future = NaiveDate::from_ymd_opt(9999, 12, 31).unwrap();
+    /// assert!(cal.business_days_remaining_until_today(far_future).is_some());
+    /// ```
+    pub fn business_days_remaining_until_today(&self, target: NaiveDate) -> Option<u32> {
+        self.business_days_remaining_until(target, Local::now().date_naive())
+    }
+
+    /// Returns `true` if the settlement date is exactly `expected_lag` business days after the
+    /// trade date. Both dates must be business days, and settlement cannot precede the trade.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.is_settlement_lag_valid(thu, mon, 2), true);
+    /// assert_eq!(cal.is_settlement_lag_valid(thu, mon, 1), false);
+    /// ```
+    pub fn is_settlement_lag_valid(
+        &self,
+        trade_date: NaiveDate,
+        settlement_date: NaiveDate,
+        expected_lag: u32,
+    ) -> bool {
+        if settlement_date < trade_date
+            || !self.is_business_day(trade_date)
+            || !self.is_business_day(settlement_date)
+        {
+            return false;
+        }
+        self.add_business_days(trade_date, u64::from(expected_lag)) == settlement_date
+    }
+
+    /// Returns every business day from `min_lag` to `max_lag` business days after the trade
+    /// date, inclusive, in chronological order. Returns an empty list if `min_lag` is greater than
+    /// `max_lag`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.settlement_window(thu, 1, 2), vec![fri, mon]);
+    /// ```
+    pub fn settlement_window(
+        &self,
+        trade_date: NaiveDate,
+        min_lag: u32,
+        max_lag: u32,
+    ) -> Vec<NaiveDate> {
+        if min_lag > max_lag {
+            return Vec::new();
+        }
+        let mut window = Vec::with_capacity((max_lag - min_lag) as usize + 1);
+        let mut date = self.add_business_days(trade_date, u64::from(min_lag));
+        window.push(date);
+        for _ in min_lag..max_lag {
+            date = self.next_business_day(date);
+            window.push(date);
+        }
+        window
+    }
+
+    /// Counts the business days in `[start, end]` for each working day of the week. Every working
+    /// day has an entry, even if none of its occurrences in the range were business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 3).unwrap()]);
+    /// let start = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2022, 10, 14).unwrap();
+    /// let counts = cal.business_day_count_by_weekday(start, end);
+    /// assert_eq!(counts[&Weekday::Mon], 1);
+    /// assert_eq!(counts[&Weekday::Fri], 2);
+    /// assert_eq!(counts.get(&Weekday::Sat), None);
+    /// ```
+    pub fn business_day_count_by_weekday(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> HashMap<Weekday, u32> {
+        let mut counts: HashMap<_, _> = self.working_days.iter().map(|&day| (day, 0)).collect();
+        let mut date = start;
+        while date <= end {
+            if self.is_business_day(date) {
+                *counts.get_mut(&date.weekday()).unwrap() += 1;
+            }
+            date += Duration::days(1);
+        }
+        counts
+    }
+
+    /// Returns the business days in `[start, end]` that fall on the given weekday, in
+    /// chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let good_friday = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+    /// let cal = Calendar::with_holidays(&[good_friday]);
+    /// let start = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 4, 5).unwrap();
+    ///
+    /// assert_eq!(
+    ///     cal.generate_weekly_business_days(start, end, Weekday::Fri),
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 4, 5).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn generate_weekly_business_days(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        weekday: Weekday,
+    ) -> Vec<NaiveDate> {
+        let days_ahead =
+            (7 + weekday.num_days_from_monday() - start.weekday().num_days_from_monday()) % 7;
+        let mut dates = Vec::new();
+        let mut date = start + Duration::days(i64::from(days_ahead));
+        while date <= end {
+            if self.is_business_day(date) {
+                dates.push(date);
+            }
+            date += Duration::weeks(1);
+        }
+        dates
+    }
+
+    /// Counts the business days in `[start, end]` for which the predicate returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{Datelike, NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let good_friday = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+    /// let cal = Calendar::with_holidays(&[good_friday]);
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    ///
+    /// let fridays =
+    ///     cal.count_business_days_matching(start, end, |date| date.weekday() == Weekday::Fri);
+    /// assert_eq!(fridays, 12);
+    /// ```
+    pub fn count_business_days_matching<F: Fn(NaiveDate) -> bool>(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        predicate: F,
+    ) -> u32 {
+        let mut count = 0;
+        let mut date = start;
+        while date <= end {
+            if self.is_business_day(date) && predicate(date) {
+                count += 1;
+            }
+            date += Duration::days(1);
+        }
+        count
+    }
+
+    /// Returns `true` if the date is the first day of its month and a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let tue = NaiveDate::from_ymd_opt(2022, 11, 1).unwrap();
+    /// assert_eq!(cal.is_business_day_and_first_of_month(sat), false);
+    /// assert_eq!(cal.is_business_day_and_first_of_month(tue), true);
+    /// ```
+    pub fn is_business_day_and_first_of_month(&self, date: NaiveDate) -> bool {
+        date.day() == 1 && self.is_business_day(date)
+    }
+
+    /// Returns `true` if the date is the last day of its month and a business day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+    /// assert_eq!(cal.is_business_day_and_last_of_month(fri), true);
+    /// assert_eq!(cal.is_business_day_and_last_of_month(sat), false);
+    /// ```
+    pub fn is_business_day_and_last_of_month(&self, date: NaiveDate) -> bool {
+        date == last_day_of_month(date.year(), date.month()) && self.is_business_day(date)
+    }
+
+    /// Counts the holidays falling on the given weekday, either in a particular year or, if
+    /// `year` is `None`, across all years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[
+    ///     NaiveDate::from_ymd_opt(2017, 12, 25).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+    /// ]);
+    /// assert_eq!(cal.count_holidays_on_weekday(Weekday::Mon, None), 2);
+    /// assert_eq!(cal.count_holidays_on_weekday(Weekday::Mon, Some(2023)), 1);
+    /// ```
+    pub fn count_holidays_on_weekday(&self, weekday: Weekday, year: Option<i32>) -> u32 {
+        self.holidays
+            .iter()
+            .filter(|date| date.weekday() == weekday)
+            .filter(|date| year.is_none() || year == Some(date.year()))
+            .count() as u32
+    }
+
+    /// Returns every year with at least one holiday, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[
+    ///     NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2020, 12, 25).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2020, 12, 28).unwrap(),
+    /// ]);
+    /// assert_eq!(cal.holidays_years(), vec![2020, 2023]);
+    /// ```
+    pub fn holidays_years(&self) -> Vec<i32> {
+        let years: BTreeSet<_> = self.holidays.iter().map(|date| date.year()).collect();
+        years.into_iter().collect()
+    }
+
+    /// Moves the holidays of `source_year` to the same month and day in `target_year`, in
+    /// chronological order. A holiday on the 29th of February moves to the 28th when the target
+    /// is not a leap year, appearing once if the 28th is also a holiday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[
+    ///     NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2023, 7, 4).unwrap(),
+    /// ]);
+    /// assert_eq!(
+    ///     cal.standardize_holidays_for_year(2024, 2025),
+    ///     vec![
+    ///         NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2025, 12, 25).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn standardize_holidays_for_year(
+        &self,
+        source_year: i32,
+        target_year: i32,
+    ) -> Vec<NaiveDate> {
+        let holidays: BTreeSet<_> = self
+            .holidays
+            .iter()
+            .filter(|date| date.year() == source_year)
+            .map(|date| {
+                date.with_year(target_year).unwrap_or_else(|| {
+                    NaiveDate::from_ymd_opt(target_year, 2, 28).expect("year out of range")
+                })
+            })
+            .collect();
+        holidays.into_iter().collect()
+    }
+
+    /// Groups the holidays by the weekday they fall on, with the dates for each weekday in
+    /// chronological order. Weekdays without any holidays are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let xmas_2017 = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+    /// let xmas_2022 = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+    /// let xmas_2023 = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+    /// let cal = Calendar::with_holidays(&[xmas_2023, xmas_2022, xmas_2017]);
+    ///
+    /// let by_weekday = cal.holidays_by_weekday();
+    /// assert_eq!(by_weekday[&Weekday::Mon], vec![xmas_2017, xmas_2023]);
+    /// assert_eq!(by_weekday[&Weekday::Sun], vec![xmas_2022]);
+    /// assert_eq!(by_weekday.get(&Weekday::Tue), None);
+    /// ```
+    pub fn holidays_by_weekday(&self) -> HashMap<Weekday, Vec<NaiveDate>> {
+        let mut by_weekday: HashMap<_, Vec<_>> = HashMap::new();
+        for &date in &self.holidays {
+            by_weekday.entry(date.weekday()).or_default().push(date);
+        }
+        for dates in by_weekday.values_mut() {
+            dates.sort_unstable();
+        }
+        by_weekday
+    }
+
+    /// Returns the first and last business days of the ISO week containing `date`, or `None` if
+    /// the week has no business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 9).unwrap();
+    /// assert_eq!(cal.stretch_to_business_week(sun), Some((mon, fri)));
+    /// ```
+    pub fn stretch_to_business_week(&self, date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let mut business_days = (0..7)
+            .map(|offset| week_start(date) + Duration::days(offset))
+            .filter(|date| self.is_business_day(*date));
+        let first = business_days.next()?;
+        Some((first, business_days.next_back().unwrap_or(first)))
+    }
+
+    /// Returns `true` if the given year has at least `min_days` consecutive non-business days.
+    /// Only days within the year are counted, so a closure spanning new year counts separately in
+    /// each year. Stops as soon as a long enough stretch is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[
+    ///     NaiveDate::from_ymd_opt(2020, 12, 24).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2020, 12, 25).unwrap(),
+    /// ]);
+    /// assert_eq!(cal.contains_long_holiday_stretch(2020, 4), true);
+    /// assert_eq!(cal.contains_long_holiday_stretch(2020, 5), false);
+    /// ```
+    pub fn contains_long_holiday_stretch(&self, year: i32, min_days: u32) -> bool {
+        if min_days == 0 {
+            return true;
+        }
+        let mut stretch = 0;
+        for date in first_day_of_month(year, 1)
+            .iter_days()
+            .take_while(|date| date.year() == year)
+        {
+            if self.is_business_day(date) {
+                stretch = 0;
+            } else {
+                stretch += 1;
+                if stretch >= min_days {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns every run of consecutive non-business days in the given year that is at least
+    /// `min_length` days long, as `(start, end, length)` in chronological order. As with
+    /// [`contains_long_holiday_stretch`](Self::contains_long_holiday_stretch), runs are cut at
+    /// the edges of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 12, 24).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+    /// assert_eq!(cal.holiday_gap_analysis(2022, 3), vec![(sat, mon, 3)]);
+    /// assert_eq!(cal.holiday_gap_analysis(2022, 2).len(), 52);
+    /// ```
+    pub fn holiday_gap_analysis(
+        &self,
+        year: i32,
+        min_length: u32,
+    ) -> Vec<(NaiveDate, NaiveDate, u32)> {
+        let mut gaps = Vec::new();
+        let mut gap: Option<(NaiveDate, NaiveDate, u32)> = None;
+        for date in first_day_of_month(year, 1)
+            .iter_days()
+            .take_while(|date| date.year() == year)
+        {
+            if !self.is_business_day(date) {
+                gap = Some(match gap {
+                    Some((start, _, length)) => (start, date, length + 1),
+                    None => (date, date, 1),
+                });
+            } else if let Some(finished) = gap.take() {
+                gaps.push(finished);
+            }
+        }
+        gaps.extend(gap);
+        gaps.retain(|&(_, _, length)| length >= min_length);
+        gaps
+    }
+
+    /// Returns the fraction of the days in the given month that are business days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not between 1 and 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use business::Calendar;
+    /// // 20 business days in February 2022
+    /// assert_eq!(Calendar::workweek().days_in_month_business_fraction(2022, 2), 20.0 / 28.0);
+    /// ```
+    pub fn days_in_month_business_fraction(&self, year: i32, month: u32) -> f64 {
+        let days = last_day_of_month(year, month).day();
+        self.business_days_in_month(year, month) as f64 / days as f64
+    }
+
+    /// Returns the fraction of the business days in the month of `date` that fall on or before
+    /// `date`. Returns NaN if the month has no business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// // 5th of 20 business days in February 2022
+    /// let date = NaiveDate::from_ymd_opt(2022, 2, 7).unwrap();
+    /// assert_eq!(cal.elapsed_business_fraction(date), 0.25);
+    /// ```
+    pub fn elapsed_business_fraction(&self, date: NaiveDate) -> f64 {
+        let elapsed =
+            self.count_business_days_inclusive(first_day_of_month(date.year(), date.month()), date);
+        elapsed as f64 / self.business_days_in_month(date.year(), date.month()) as f64
+    }
+
+    /// Linearly interpolates between two values, using business days as the time axis. Returns
+    /// `None` if `query_date` is outside `[start_date, end_date]`, and `start_val` if there are
+    /// no business days between the two dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 10, 6).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 8).unwrap();
+    /// let next_mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+    ///
+    /// // 3 of the 5 business days between the two dates
+    /// assert_eq!(cal.interpolate_business_value(mon, 1.0, next_mon, 2.0, thu), Some(1.6));
+    /// // The weekend is flat
+    /// assert_eq!(cal.interpolate_business_value(mon, 1.0, next_mon, 2.0, sat), Some(2.0));
+    /// assert_eq!(cal.interpolate_business_value(thu, 1.0, next_mon, 2.0, mon), None);
+    /// ```
+    pub fn interpolate_business_value(
+        &self,
+        start_date: NaiveDate,
+        start_val: f64,
+        end_date: NaiveDate,
+        end_val: f64,
+        query_date: NaiveDate,
+    ) -> Option<f64> {
+        if query_date < start_date || query_date > end_date {
+            return None;
+        }
+        let total = self.business_days_between(start_date, end_date);
+        if total == 0 {
+            return Some(start_val);
+        }
+        let elapsed = self.business_days_between(start_date, query_date);
+        Some(start_val + (end_val - start_val) * elapsed as f64 / total as f64)
+    }
+
+    /// Returns the business day at the given percentile of the business days in `year`, where
+    /// `0.0` is the first business day and `1.0` the last.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percentile` is not between 0 and 1, or if the year has no business days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// // 234th of 260 business days in 2023
+    /// let date = NaiveDate::from_ymd_opt(2023, 11, 23).unwrap();
+    /// assert_eq!(cal.business_day_at_percentile(2023, 0.9), date);
+    /// ```
+    pub fn business_day_at_percentile(&self, year: i32, percentile: f64) -> NaiveDate {
+        assert!(
+            (0.0..=1.0).contains(&percentile),
+            "percentile must be between 0 and 1"
+        );
+        let n = (percentile * self.business_days_in_year(year) as f64).ceil() as u32;
+        self.nth_business_day_of_year(year, n.max(1))
+            .expect("year has no business days")
+    }
+
+    /// Returns the business day nearest to the given date, which is the date itself if it is a
+    /// business day. When the previous and next business days are equally far away, the next one
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.nearest_business_day(sat), fri);
+    /// assert_eq!(cal.nearest_business_day(sun), mon);
+    /// assert_eq!(cal.nearest_business_day(mon), mon);
+    /// ```
+    pub fn nearest_business_day(&self, date: NaiveDate) -> NaiveDate {
+        date + Duration::days(i64::from(self.business_day_skew(date)))
+    }
+
+    /// Returns the business day nearest to the day at the given fraction of the month, e.g. the
+    /// middle of the month for `0.5`. The target day is `fraction` times the number of days in the
+    /// month, rounded to the nearest day and kept within the month. The business day returned can
+    /// fall in an adjacent month if the target is close to the start or end of the month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not between 1 and 12, or if `fraction` is not between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// // The 15th of June 2024 is a Saturday
+    /// assert_eq!(
+    ///     cal.business_day_nearest_to_month_fraction(2024, 6, 0.5),
+    ///     NaiveDate::from_ymd_opt(2024, 6, 14).unwrap()
+    /// );
+    /// ```
+    pub fn business_day_nearest_to_month_fraction(
+        &self,
+        year: i32,
+        month: u32,
+        fraction: f64,
+    ) -> NaiveDate {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "fraction must be between 0 and 1"
+        );
+        let last = last_day_of_month(year, month);
+        let day = ((fraction * last.day() as f64).round() as u32).max(1);
+        self.nearest_business_day(last.with_day(day).expect("day is within the month"))
+    }
+
+    /// Returns the earliest business day on or after every one of the given dates, e.g. the first
+    /// day a meeting can be held once everyone is available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dates` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// assert_eq!(cal.find_earliest_common_business_day(&[sat, thu]), mon);
+    /// ```
+    pub fn find_earliest_common_business_day(&self, dates: &[NaiveDate]) -> NaiveDate {
+        let latest = dates.iter().max().expect("dates must not be empty");
+        self.roll_forward(*latest)
+    }
+
+    /// Returns the earliest date on or after every one of the given dates that is a business day
+    /// in all of the calendars.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dates` is empty, or if no day of the week is a working day in all of the
+    /// calendars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    /// let uk = Calendar::workweek();
+    /// let us = Calendar::with_holidays(&[fri]);
+    ///
+    /// assert_eq!(
+    ///     Calendar::find_earliest_common_business_day_multi(&[fri], &[&uk, &us]),
+    ///     mon
+    /// );
+    /// ```
+    pub fn find_earliest_common_business_day_multi(
+        dates: &[NaiveDate],
+        calendars: &[&Calendar],
+    ) -> NaiveDate {
+        let mut date = *dates.iter().max().expect("dates must not be empty");
+        let shares_working_day = calendars.first().map_or(true, |first| {
+            first
+                .working_days
+                .iter()
+                .any(|day| calendars.iter().all(|cal| cal.working_days.contains(day)))
+        });
+        assert!(
+            shares_working_day,
+            "calendars have no working days in common"
+        );
+        while !calendars.iter().all(|cal| cal.is_business_day(date)) {
+            date += Duration::days(1);
+        }
+        date
+    }
+
+    /// Merges two calendars so that a day is a business day only if it is one in both, e.g. for
+    /// a payment that needs both markets to be open. Working days are those shared by both
+    /// calendars, and holidays are those of either. Holidays named by both calendars keep the
+    /// name from `self` first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::NoWorkingDays`] if the calendars have no working days in common,
+    /// as the merged calendar would have no business days at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+    /// let uk = Calendar::with_holidays(&[xmas, boxing_day]);
+    /// let us = Calendar::with_holidays(&[xmas]);
+    ///
+    /// let merged = uk.merge(&us).unwrap();
+    /// assert_eq!(merged.is_business_day(xmas), false);
+    /// assert_eq!(merged.is_business_day(boxing_day), false);
+    /// ```
+    pub fn merge(&self, other: &Calendar) -> Result<Calendar, CalendarError> {
+        let working_days: HashSet<_> = self
+            .working_days
+            .intersection(&other.working_days)
+            .cloned()
+            .collect();
+        if working_days.is_empty() {
+            return Err(CalendarError::NoWorkingDays);
+        }
+
+        let holidays: HashSet<_> = self.holidays.union(&other.holidays).cloned().collect();
+        let mut merged = Calendar::from_parts(working_days, holidays);
+        merged.add_holiday_names_from(self);
+        merged.add_holiday_names_from(other);
+        Ok(merged)
+    }
+
+    /// Merges two calendars, taking the working days from `high` alone and the holidays of both,
+    /// e.g. for a subsidiary that follows its parent's working week but adds local holidays.
+    /// Holidays named by both calendars keep the name from `high` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Weekday};
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+    /// let mut parent = Calendar::with_holidays(&[xmas]);
+    /// parent.working_days.remove(&Weekday::Fri);
+    /// let local = Calendar::with_holidays(&[boxing_day]);
+    ///
+    /// let merged = Calendar::merge_with_priority(parent, local);
+    /// assert_eq!(merged.working_days.len(), 4);
+    /// assert_eq!(merged.is_business_day(boxing_day), false);
+    /// ```
+    pub fn merge_with_priority(high: Calendar, low: Calendar) -> Calendar {
+        let mut merged = Calendar::from_parts(
+            high.working_days.clone(),
+            high.holidays.union(&low.holidays).cloned().collect(),
+        );
+        merged.add_holiday_names_from(&high);
+        merged.add_holiday_names_from(&low);
+        merged
+    }
+
+    /// Merges two calendars so that a day is a business day if it is one in either, e.g. for
+    /// an office that stays open as long as one of its teams is working. Working days are those
+    /// of either calendar, and holidays are only kept on dates that neither calendar treats as a
+    /// business day. Holidays named by both calendars keep the name from `self` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+    /// let boxing_day = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+    /// let uk = Calendar::with_holidays(&[xmas, boxing_day]);
+    /// let us = Calendar::with_holidays(&[xmas]);
+    ///
+    /// let merged = uk.merge_prefer_open(&us);
+    /// assert_eq!(merged.is_business_day(xmas), false);
+    /// assert_eq!(merged.is_business_day(boxing_day), true);
+    /// ```
+    pub fn merge_prefer_open(&self, other: &Calendar) -> Calendar {
+        let holidays: HashSet<_> = self
+            .holidays
+            .union(&other.holidays)
+            .filter(|&&date| !self.is_business_day(date) && !other.is_business_day(date))
+            .cloned()
+            .collect();
+        let mut merged = Calendar::from_parts(
+            self.working_days
+                .union(&other.working_days)
+                .cloned()
+                .collect(),
+            holidays,
+        );
+        merged.add_holiday_names_from(self);
+        merged.add_holiday_names_from(other);
+        merged
+    }
+
+    /// Counts the days in `[start, end]` that are business days in both this calendar and
+    /// `other`, e.g. to check the business days available for an FX settlement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let uk = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 8, 29).unwrap()]);
+    /// let us = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 9, 5).unwrap()]);
+    /// let mon = NaiveDate::from_ymd_opt(2022, 8, 29).unwrap();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 9).unwrap();
+    /// assert_eq!(uk.overlap_with(&us, mon, fri), 8);
+    /// ```
+    pub fn overlap_with(&self, other: &Calendar, start: NaiveDate, end: NaiveDate) -> u32 {
+        let mut count = 0;
+        let mut date = start;
+        while date <= end {
+            if self.is_business_day(date) && other.is_business_day(date) {
+                count += 1;
+            }
+            date += Duration::days(1);
+        }
+        count
+    }
+
+    /// Counts the business days in the intersection of two periods, each inclusive of both ends.
+    /// Returns `0` if the periods do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::workweek();
+    /// let ymd = |day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap();
+    /// assert_eq!(cal.period_business_day_overlap((ymd(1), ymd(12)), (ymd(10), ymd(31))), 3);
+    /// assert_eq!(cal.period_business_day_overlap((ymd(1), ymd(9)), (ymd(10), ymd(31))), 0);
+    /// ```
+    pub fn period_business_day_overlap(
+        &self,
+        period1: (NaiveDate, NaiveDate),
+        period2: (NaiveDate, NaiveDate),
+    ) -> u32 {
+        self.count_business_days_inclusive(period1.0.max(period2.0), period1.1.min(period2.1))
+    }
+
+    /// Splits `[period_start, period_end]` into the runs of consecutive business days within it,
+    /// returned as inclusive `(start, end)` pairs in chronological order. Any non-business day,
+    /// including weekends, ends a run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::Calendar;
+    /// let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 5).unwrap()]);
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let sun = NaiveDate::from_ymd_opt(2022, 10, 9).unwrap();
+    /// let ymd = |day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap();
+    /// assert_eq!(
+    ///     cal.intersect_with_non_holiday_period(sat, sun),
+    ///     vec![(ymd(3), ymd(4)), (ymd(6), ymd(7))]
+    /// );
+    /// ```
+    pub fn intersect_with_non_holiday_period(
+        &self,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut periods = Vec::new();
+        let mut run: Option<(NaiveDate, NaiveDate)> = None;
+        let mut date = period_start;
+        while date <= period_end {
+            if self.is_business_day(date) {
+                run = Some((run.map_or(date, |(start, _)| start), date));
+            } else if let Some(period) = run.take() {
+                periods.push(period);
+            }
+            date += Duration::days(1);
+        }
+        periods.extend(run);
+        periods
+    }
+
+    /// Adds the names that `other` gives to this calendar's holidays, after any names they
+    /// already have.
+    fn add_holiday_names_from(&mut self, other: &Calendar) {
+        let mut holidays: Vec<_> = self.holidays.iter().cloned().collect();
+        holidays.sort_unstable();
+        for date in holidays {
+            for name in other.holiday_names_on(date) {
+                self.add_holiday_name(date, name);
+            }
+        }
+    }
+
+    /// Groups the days of the year by month, keeping those whose business day status matches
+    /// `is_business`.
+    fn days_by_month(&self, year: i32, is_business: bool) -> HashMap<u32, Vec<NaiveDate>> {
+        (1..=12)
+            .map(|month| {
+                let last = last_day_of_month(year, month);
+                let business_days = self.business_days_in_month(year, month) as usize;
+                let capacity = if is_business {
+                    business_days
+                } else {
+                    last.day() as usize - business_days
+                };
+                let mut days = Vec::with_capacity(capacity);
+                let mut date = first_day_of_month(year, month);
+                while date <= last {
+                    if self.is_business_day(date) == is_business {
+                        days.push(date);
+                    }
+                    date += Duration::days(1);
+                }
+                (month, days)
+            })
+            .collect()
+    }
+
+    /// Counts business days in `[start, end]`.
+    fn count_business_days_inclusive(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        self.count_business_days_matching(start, end, |_| true)
+    }
+}
+
+/// Materializes the calendar for every date from the start of the year before its earliest
+/// holiday, to the end of the year after its latest holiday. A calendar without holidays gives an
+/// empty map; use [`Calendar::to_day_map`] to choose the range explicitly.
+impl From<Calendar> for HashMap<NaiveDate, bool> {
+    fn from(cal: Calendar) -> Self {
+        let years = cal.holidays.iter().map(|date| date.year());
+        match (years.clone().min(), years.max()) {
+            (Some(min), Some(max)) => cal.to_day_map(
+                first_day_of_month(min - 1, 1),
+                last_day_of_month(max + 1, 12),
+            ),
+            _ => HashMap::new(),
+        }
+    }
+}
+
+/// Builds a calendar from its working days and holidays, checking that there is at least one
+/// working day. Without any, most calculations would never find a business day.
+///
+/// ```
+/// # use chrono::{NaiveDate, Weekday};
+/// # use business::{Calendar, CalendarError};
+/// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+/// let cal = Calendar::try_from((vec![Weekday::Mon, Weekday::Fri], vec![xmas])).unwrap();
+/// assert_eq!(cal.is_business_day(xmas), false);
+///
+/// let err = Calendar::try_from((vec![], vec![xmas])).unwrap_err();
+/// assert_eq!(err, CalendarError::NoWorkingDays);
+/// ```
+impl TryFrom<(Vec<Weekday>, Vec<NaiveDate>)> for Calendar {
+    type Error = CalendarError;
+
+    fn try_from(
+        (working_days, holidays): (Vec<Weekday>, Vec<NaiveDate>),
+    ) -> Result<Self, Self::Error> {
+        if working_days.is_empty() {
+            return Err(CalendarError::NoWorkingDays);
+        }
+
+        Ok(Calendar::from_parts(
+            working_days.into_iter().collect(),
+            holidays.into_iter().collect(),
+        ))
+    }
+}
+
+fn workweek() -> HashSet<Weekday> {
+    WORKWEEK.iter().cloned().collect()
+}
+
+fn first_day_of_month(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, 1).expect("invalid year or month")
+}
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months))
+        .expect("date out of range")
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+fn last_day_of_month(year: i32, month: u32) -> NaiveDate {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    first_day_of_month(next_year, next_month) - Duration::days(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sat_is_not_business() {
+        let cal = Calendar::workweek();
+        let saturday = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+
+        assert!(!cal.is_business_day(saturday));
+    }
+
+    #[test]
+    fn mon_is_business() {
+        let cal = Calendar::workweek();
+        let monday = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+
+        assert!(cal.is_business_day(monday));
+    }
+
+    #[test]
+    fn mon_holiday_is_not_business() {
+        let monday = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[monday]);
+
+        assert!(!cal.is_business_day(monday));
+    }
+
+    #[test]
+    fn sat_rolls_forward_to_tues() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let business_tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        assert_eq!(cal.roll_forward(sat), business_tue);
+    }
+
+    #[test]
+    fn mon_rolls_forward_same_day() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::workweek();
+
+        assert_eq!(cal.roll_forward(mon), mon);
+    }
+
+    #[test]
+    fn sun_rolls_backward_to_thu() {
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+
+        assert_eq!(cal.roll_backward(sun), business_thu);
+    }
+
+    #[test]
+    fn mon_rolls_backward_same_day() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::workweek();
+
+        assert_eq!(cal.roll_backward(mon), mon);
+    }
+
+    #[test]
+    fn sat_next_business_is_tues() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let business_tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        assert_eq!(cal.next_business_day(sat), business_tue);
+    }
+
+    #[test]
+    fn mon_next_business_is_tues() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[]);
+
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        assert_eq!(cal.next_business_day(mon), tue);
+    }
+
+    #[test]
+    fn sun_previous_business_is_thu() {
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+
+        assert_eq!(cal.previous_business_day(sun), business_thu);
+    }
+
+    #[test]
+    fn mon_previous_business_is_fri() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        assert_eq!(cal.previous_business_day(mon), fri);
+    }
+
+    #[test]
+    fn sat_add_2_business_is_thu() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_tues = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_tues]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 10, 6).unwrap();
+
+        assert_eq!(cal.add_business_days(sat, 2), business_thu);
+    }
+
+    #[test]
+    fn mon_add_2_business_is_wed() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::workweek();
+
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        assert_eq!(cal.add_business_days(mon, 2), wed);
+    }
+
+    #[test]
+    fn sun_sub_2_business_is_thu() {
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 27).unwrap();
+
+        assert_eq!(cal.subtract_business_days(sun, 2), business_thu);
+    }
+
+    #[test]
+    fn sub_from_non_business_day_rolls_backward_like_ruby_gem() {
+        // The Ruby gem rolls a non-business start date backward before subtracting, so counting
+        // from a weekend starts at the Friday before rather than the Monday after
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 9, 29).unwrap()]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 9, 28).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 9, 27).unwrap();
+
+        assert_eq!(cal.subtract_business_days(sat, 0), fri);
+        assert_eq!(cal.subtract_business_days(sat, 1), wed);
+        assert_eq!(cal.subtract_business_days(sat, 2), tue);
+    }
+
+    #[test]
+    fn wed_sub_2_business_is_mon() {
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let cal = Calendar::workweek();
+
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+
+        assert_eq!(cal.subtract_business_days(wed, 2), mon);
+    }
+
+    #[test]
+    fn parse_yaml() {
+        let input = "
+            working_days:
+                - monday
+                - tuesday
+                - friday
+
+            holidays:
+              - 2022-01-01
+              - 2012-12-25
+        ";
+        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+
+        let expected = Calendar::from_parts(
+            HashSet::from([Weekday::Mon, Weekday::Tue, Weekday::Fri]),
+            HashSet::from([
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2012, 12, 25).unwrap(),
+            ]),
+        );
+
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn parse_yaml_with_defaults() {
+        let input = "
+            holidays:
+              - 2022-01-01
+              - 2012-12-25
+        ";
+        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+
+        let expected = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2012, 12, 25).unwrap(),
+        ]);
+
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn thu_fri_holidays_make_4_day_break() {
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let holiday_thu = NaiveDate::from_ymd_opt(2022, 10, 6).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 8).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_thu, holiday_fri]);
+
+        assert_eq!(cal.adjacent_non_business_days(wed), (0, 4));
+        assert_eq!(cal.adjacent_non_business_days(holiday_thu), (0, 4));
+        assert_eq!(cal.adjacent_non_business_days(holiday_fri), (1, 3));
+        assert_eq!(cal.adjacent_non_business_days(sat), (2, 2));
+        assert_eq!(cal.adjacent_non_business_days(mon), (4, 0));
+    }
+
+    #[test]
+    fn q4_period_spans_oct_to_dec() {
+        let cal = Calendar::workweek();
+        let q4 = cal.for_quarter(2022, 4);
+
+        assert_eq!(q4.start(), NaiveDate::from_ymd_opt(2022, 10, 1).unwrap());
+        assert_eq!(q4.end(), NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn quarter_business_days_skip_holidays() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+        let q2 = cal.for_quarter(2024, 2);
+
+        let business_days = q2.business_days();
+        assert_eq!(business_days.len(), 64);
+        assert_eq!(
+            business_days[0],
+            NaiveDate::from_ymd_opt(2024, 4, 2).unwrap()
+        );
+        assert_eq!(
+            business_days[63],
+            NaiveDate::from_ymd_opt(2024, 6, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn dec_period_ends_on_31st() {
+        let cal = Calendar::workweek();
+        let dec = cal.for_month(2022, 12);
+
+        assert_eq!(dec.end(), NaiveDate::from_ymd_opt(2022, 12, 31).unwrap());
+        assert!(dec.contains(NaiveDate::from_ymd_opt(2022, 12, 25).unwrap()));
+        assert!(!dec.contains(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn quarter_5_panics() {
+        Calendar::workweek().for_quarter(2022, 5);
+    }
+
+    #[test]
+    fn next_3_business_days_after_sat_skip_holiday() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let expected = vec![
+            NaiveDate::from_ymd_opt(2022, 10, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 6).unwrap(),
+        ];
+
+        assert_eq!(cal.next_n_business_days(sat, 3), expected);
+    }
+
+    #[test]
+    fn next_n_business_days_excludes_business_start() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::workweek();
+
+        let days = cal.next_n_business_days(mon, 5);
+        assert_eq!(days.len(), 5);
+        assert!(!days.contains(&mon));
+        assert!(cal.next_n_business_days(mon, 0).is_empty());
+    }
+
+    #[test]
+    fn previous_2_business_days_before_mon_skip_holiday() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let expected = vec![
+            NaiveDate::from_ymd_opt(2022, 9, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 9, 28).unwrap(),
+        ];
+
+        assert_eq!(cal.previous_n_business_days(mon, 2), expected);
+    }
+
+    #[test]
+    fn position_skips_holidays() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let holiday_mon_end = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon, holiday_mon_end]);
+
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let expected = BusinessDayPosition {
+            index_in_month: 1,
+            index_in_year: 196,
+            index_from_month_end: 18,
+        };
+
+        assert_eq!(cal.business_day_position(tue), Some(expected));
+    }
+
+    #[test]
+    fn last_business_day_before_holiday_month_end() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 10, 28).unwrap();
+        assert_eq!(cal.business_day_before_month_end(fri), Some(0));
+    }
+
+    #[test]
+    fn holiday_has_no_position() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        assert_eq!(cal.business_day_position(holiday_mon), None);
+        assert_eq!(cal.business_day_of_month_index(holiday_mon), None);
+        assert_eq!(cal.business_day_of_year_index(holiday_mon), None);
+    }
+
+    #[test]
+    fn leap_year_has_extra_business_day() {
+        let cal = Calendar::workweek();
+
+        assert_eq!(cal.business_days_in_year(2023), 260);
+        assert_eq!(cal.business_days_in_year(2024), 262);
+    }
+
+    #[test]
+    fn estimate_averages_over_holidays() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 12, 26).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 26).unwrap(),
+        ]);
+
+        assert_eq!(cal.estimate_business_days_per_year(2022..=2023), 258.5);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn estimate_for_empty_range_is_nan() {
+        let cal = Calendar::workweek();
+
+        assert!(cal.estimate_business_days_per_year(2023..=2022).is_nan());
+    }
+
+    #[test]
+    fn day_map_covers_range_inclusive() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let start = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let map = cal.to_day_map(start, end);
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.values().filter(|&&b| b).count(), 2);
+        assert!(!map[&holiday_mon]);
+        assert!(map[&end]);
+    }
+
+    #[test]
+    fn day_map_from_calendar_spans_holiday_years() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 12, 26).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+        ]);
+
+        let map: HashMap<NaiveDate, bool> = cal.into();
+
+        assert_eq!(map.len(), 365 + 365 + 365 + 366);
+        assert!(map.contains_key(&NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()));
+        assert!(map.contains_key(&NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()));
+        assert!(!map[&NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
+    }
+
+    #[test]
+    fn day_map_from_calendar_without_holidays_is_empty() {
+        let map: HashMap<NaiveDate, bool> = Calendar::workweek().into();
+
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn add_and_subtract_accept_u64_deltas() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::workweek();
+
+        let weeks: u64 = 52;
+        let delta = weeks * 5;
+
+        let year_later = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap();
+        assert_eq!(cal.add_business_days(mon, delta), year_later);
+        assert_eq!(cal.subtract_business_days(year_later, delta), mon);
+    }
+
+    #[test]
+    fn sat_is_3_days_until_business_tues() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        assert_eq!(cal.days_until_business_day(sat), 3);
+    }
+
+    #[test]
+    fn mon_holiday_is_3_days_since_business_fri() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        assert_eq!(cal.days_since_business_day(holiday_mon), 3);
+    }
+
+    #[test]
+    fn on_or_after_and_on_or_before_match_rolling() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        assert_eq!(
+            cal.business_day_on_or_after(holiday_mon),
+            cal.roll_forward(holiday_mon)
+        );
+        assert_eq!(
+            cal.business_day_on_or_before(holiday_mon),
+            cal.roll_backward(holiday_mon)
+        );
+    }
+
+    #[test]
+    fn calendar_macro_builds_calendar() {
+        let cal = calendar!(
+            working_days: [Monday, Tue, Friday],
+            holidays: [2022-01-01, 2012-12-25],
+        );
+
+        let expected = Calendar::from_parts(
+            HashSet::from([Weekday::Mon, Weekday::Tue, Weekday::Fri]),
+            HashSet::from([
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2012, 12, 25).unwrap(),
+            ]),
+        );
+
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn calendar_macro_allows_no_holidays() {
+        let cal = calendar!(working_days: [Mon, Tue, Wed, Thu, Fri], holidays: []);
+
+        assert_eq!(cal, Calendar::workweek());
+    }
+
+    #[test]
+    fn rolling_count_over_xmas_break() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2017, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2017, 12, 26).unwrap(),
+        ]);
+        let wed = NaiveDate::from_ymd_opt(2017, 12, 27).unwrap();
+
+        assert_eq!(cal.rolling_business_day_count(wed, 7), 3);
+        assert_eq!(cal.rolling_business_day_count(wed, 5), 1);
+    }
+
+    #[test]
+    fn rolling_count_1_day_window() {
+        let cal = Calendar::workweek();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+
+        assert_eq!(cal.rolling_business_day_count(mon, 1), 1);
+        assert_eq!(cal.rolling_business_day_count(sat, 1), 0);
+        assert_eq!(cal.rolling_business_day_count(mon, 0), 0);
+    }
+
+    #[test]
+    fn holiday_week_still_counts_as_business_week() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let start = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 10, 16).unwrap();
+
+        assert_eq!(cal.business_week_count(start, end), 2);
+        assert_eq!(cal.full_business_week_count(start, end), 1);
+    }
+
+    #[test]
+    fn weekend_only_range_has_no_full_business_week() {
+        let cal = Calendar::workweek();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+
+        assert_eq!(cal.business_week_count(sat, sun), 0);
+        assert_eq!(cal.full_business_week_count(sat, sun), 0);
+    }
+
+    #[test]
+    fn week_of_holidays_is_not_business_week() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 10, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 6).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 7).unwrap(),
+        ]);
+
+        let start = NaiveDate::from_ymd_opt(2022, 9, 26).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 10, 14).unwrap();
+
+        assert_eq!(cal.business_week_count(start, end), 2);
+        assert_eq!(cal.full_business_week_count(start, end), 2);
+    }
+
+    #[test]
+    fn partial_week_with_holiday_outside_range_is_not_full() {
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        assert_eq!(cal.business_week_count(mon, wed), 1);
+        assert_eq!(cal.full_business_week_count(mon, wed), 0);
+    }
+
+    #[test]
+    fn penultimate_business_day_skips_holiday() {
+        let holiday_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_thu]);
+
+        let wed = NaiveDate::from_ymd_opt(2022, 9, 28).unwrap();
+
+        assert!(cal.is_penultimate_business_day_of_month(wed));
+        assert!(!cal.is_penultimate_business_day_of_month(holiday_thu));
+    }
+
+    #[test]
+    fn month_with_2_business_days_has_penultimate() {
+        let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let holidays: Vec<_> = (1..=28)
+            .map(|d| NaiveDate::from_ymd_opt(2022, 9, d).unwrap())
+            .collect();
+        let cal = Calendar::with_holidays(&holidays);
+
+        assert!(cal.is_penultimate_business_day_of_month(thu));
+        assert!(!cal.is_penultimate_business_day_of_month(fri));
+    }
+
+    #[test]
+    fn month_with_1_business_day_has_no_penultimate() {
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let holidays: Vec<_> = (1..=29)
+            .map(|d| NaiveDate::from_ymd_opt(2022, 9, d).unwrap())
+            .collect();
+        let cal = Calendar::with_holidays(&holidays);
+
+        assert_eq!(cal.last_business_day_of_month(2022, 9), Some(fri));
+        assert!(!cal.is_penultimate_business_day_of_month(fri));
+    }
+
+    #[test]
+    fn month_without_business_days_has_no_last() {
+        let holidays: Vec<_> = (1..=30)
+            .map(|d| NaiveDate::from_ymd_opt(2022, 9, d).unwrap())
+            .collect();
+        let cal = Calendar::with_holidays(&holidays);
+
+        assert_eq!(cal.last_business_day_of_month(2022, 9), None);
+    }
+
+    #[test]
+    fn group_by_week_across_year_end() {
+        let cal = Calendar::workweek();
+        let fri = NaiveDate::from_ymd_opt(2021, 12, 31).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let thu = NaiveDate::from_ymd_opt(2021, 12, 30).unwrap();
+
+        let weeks = cal.group_by_business_week(&[mon, sat, fri, thu]);
+
+        let expected = BTreeMap::from([((2021, 52), vec![thu, fri, sat]), ((2022, 1), vec![mon])]);
+        assert_eq!(weeks, expected);
+    }
+
+    #[test]
+    fn effective_date_t_plus_2_skips_holiday() {
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        assert_eq!(cal.effective_date(fri, 2), Ok(wed));
+        assert_eq!(cal.effective_date(fri, 0), Ok(fri));
+    }
+
+    #[test]
+    fn effective_date_from_holiday_is_error() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let err = cal.effective_date(holiday_mon, 2).unwrap_err();
+        assert_eq!(err, CalendarError::NotABusinessDay(holiday_mon));
+        assert_eq!(err.to_string(), "2022-10-03 is not a business day");
+    }
+
+    #[test]
+    fn unadjusted_keeps_weekend() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let cal = Calendar::workweek();
+
+        assert_eq!(
+            cal.roll_with_convention(sat, RollingConvention::Unadjusted),
+            sat
+        );
+    }
+
+    #[test]
+    fn modified_following_rolls_back_at_month_end() {
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let convention = RollingConvention::ModifiedFollowing;
+
+        assert_eq!(
+            cal.roll_with_convention(holiday_fri, convention),
+            business_thu
+        );
+    }
+
+    #[test]
+    fn modified_preceding_rolls_forward_at_month_start() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let business_tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let convention = RollingConvention::ModifiedPreceding;
+
+        assert_eq!(cal.roll_with_convention(sat, convention), business_tue);
+    }
+
+    #[test]
+    fn maturity_by_tenor() {
+        let cal = Calendar::workweek();
+        let start = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap();
+        let following = RollingConvention::Following;
+
+        assert_eq!(
+            cal.maturity_date(start, Tenor::Days(5), following),
+            NaiveDate::from_ymd_opt(2022, 2, 7).unwrap()
+        );
+        assert_eq!(
+            cal.maturity_date(start, Tenor::Weeks(1), following),
+            NaiveDate::from_ymd_opt(2022, 2, 7).unwrap()
+        );
+        // Feb 28th 2022 is a Monday
+        assert_eq!(
+            cal.maturity_date(start, Tenor::Months(1), following),
+            NaiveDate::from_ymd_opt(2022, 2, 28).unwrap()
+        );
+        // Jan 31st 2027 is a Sunday
+        assert_eq!(
+            cal.maturity_date(start, Tenor::Years(5), RollingConvention::ModifiedFollowing),
+            NaiveDate::from_ymd_opt(2027, 1, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn previous_month_end_from_first_of_month() {
+        let first = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+
+        assert_eq!(cal.previous_month_end_business_day(first), business_thu);
+    }
+
+    #[test]
+    fn previous_month_end_from_month_end() {
+        let last_business = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+        let cal = Calendar::workweek();
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+
+        assert_eq!(cal.previous_month_end_business_day(last_business), fri);
+    }
+
+    #[test]
+    fn previous_month_end_from_jan_is_dec() {
+        let jan = NaiveDate::from_ymd_opt(2023, 1, 16).unwrap();
+        let holiday_sat = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 12, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_sat, holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 12, 29).unwrap();
+
+        assert_eq!(cal.previous_month_end_business_day(jan), business_thu);
+    }
+
+    #[test]
+    fn business_day_map_for_leap_year() {
+        let holiday = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[holiday]);
+
+        let map = cal.business_day_map(2024);
+
+        assert_eq!(map.iter().filter(|&&b| b).count(), 261);
+        assert!(!map[holiday.ordinal0() as usize]);
+        // Dec 31st 2024 is a Tuesday
+        assert!(map[365]);
+    }
+
+    #[test]
+    fn parse_yaml_with_historical_dates() {
+        let input = "
+            holidays:
+              - 1850-12-25
+              - 0999-12-25
+              - -0044-03-15
+        ";
+        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+
+        let expected = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(1850, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(999, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(-44, 3, 15).unwrap(),
+        ]);
+
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn calculations_before_epoch() {
+        // Christmas 1850 was a Wednesday
+        let xmas = NaiveDate::from_ymd_opt(1850, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+
+        let thu = NaiveDate::from_ymd_opt(1850, 12, 26).unwrap();
+        let tue = NaiveDate::from_ymd_opt(1850, 12, 24).unwrap();
+
+        assert!(!cal.is_business_day(xmas));
+        assert_eq!(cal.roll_forward(xmas), thu);
+        assert_eq!(cal.subtract_business_days(thu, 1), tue);
+        assert_eq!(cal.days_since_business_day(xmas), 1);
+        assert_eq!(cal.business_days_in_year(1850), 260);
+        assert_eq!(cal.business_day_of_year_index(thu), Some(257));
+    }
+
+    #[test]
+    fn calculations_before_common_era() {
+        // 15 March 44 BC is a Friday in the proleptic Gregorian calendar
+        let ides = NaiveDate::from_ymd_opt(-43, 3, 15).unwrap();
+        let cal = Calendar::with_holidays(&[ides]);
+
+        let mon = NaiveDate::from_ymd_opt(-43, 3, 18).unwrap();
+
+        assert_eq!(ides.weekday(), Weekday::Fri);
+        assert_eq!(cal.next_business_day(ides), mon);
+        assert_eq!(cal.adjacent_non_business_days(ides), (0, 3));
+    }
+
+    #[test]
+    fn skew_points_to_nearest_business_day() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_fri, holiday_mon]);
+
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+
+        assert_eq!(cal.business_day_skew(sat), -2);
+        assert_eq!(cal.business_day_skew(sun), 2);
+        assert_eq!(cal.business_day_skew(holiday_mon), 1);
+        assert_eq!(cal.business_day_skew(holiday_fri), -1);
+    }
+
+    #[test]
+    fn skew_tie_prefers_future_business_day() {
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        assert_eq!(cal.business_day_skew(sun), 2);
+    }
+
+    #[test]
+    fn excluding_duplicates_weekends_and_holidays() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let start = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 10, 8).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+
+        let exclude = [wed, wed, sun, holiday_mon];
+        assert_eq!(
+            cal.count_business_days_in_range_excluding(start, end, &exclude),
+            3
+        );
+        assert_eq!(
+            cal.count_business_days_in_range_excluding(start, end, &[]),
+            4
+        );
+    }
+
+    #[test]
+    fn excluding_ignores_end_date() {
+        let cal = Calendar::workweek();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        assert_eq!(cal.count_business_days_in_range_excluding(mon, tue, &[]), 1);
+        assert_eq!(cal.count_business_days_in_range_excluding(mon, mon, &[]), 0);
+    }
+
+    #[test]
+    fn try_from_weekdays_and_holidays() {
+        let holiday = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let working_days = vec![Weekday::Mon, Weekday::Tue, Weekday::Tue];
+
+        let cal = Calendar::try_from((working_days, vec![holiday, holiday])).unwrap();
+
+        let expected = Calendar::from_parts(
+            HashSet::from([Weekday::Mon, Weekday::Tue]),
+            HashSet::from([holiday]),
+        );
+        assert_eq!(cal, expected);
+    }
+
+    #[test]
+    fn try_from_without_working_days_is_error() {
+        let result = Calendar::try_from((vec![], vec![]));
+
+        assert_eq!(result, Err(CalendarError::NoWorkingDays));
+    }
+
+    #[test]
+    fn parse_yaml_with_holiday_names() {
+        let input = "
+            holidays:
+              - 2017-12-26
+              - 2017-12-25
+              - 2017-01-02
+            holiday_names:
+              2017-12-25: Christmas Day
+              2017-12-26: Boxing Day
+              2017-05-01: Not a holiday
+        ";
+        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+
+        let expected = vec![
+            (NaiveDate::from_ymd_opt(2017, 1, 2).unwrap(), None),
+            (
+                NaiveDate::from_ymd_opt(2017, 12, 25).unwrap(),
+                Some("Christmas Day".to_string()),
+            ),
+            (
+                NaiveDate::from_ymd_opt(2017, 12, 26).unwrap(),
+                Some("Boxing Day".to_string()),
+            ),
+        ];
+
+        assert_eq!(cal.all_holidays_as_named(), expected);
+
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let boxing_day = NaiveDate::from_ymd_opt(2017, 12, 26).unwrap();
+        let mut without_stray_name = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2017, 1, 2).unwrap(),
+            xmas,
+            boxing_day,
+        ]);
+        without_stray_name.add_holiday_name(xmas, "Christmas Day");
+        without_stray_name.add_holiday_name(boxing_day, "Boxing Day");
+        assert_eq!(cal, without_stray_name);
+    }
+
+    #[test]
+    fn add_holiday_name_ignores_dates_that_are_not_holidays() {
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let mut cal = Calendar::workweek();
+
+        cal.add_holiday_name(xmas, "Christmas Day");
+
+        assert_eq!(cal, Calendar::workweek());
+        assert_eq!(cal.holiday_name(xmas), None);
+    }
+
+    #[test]
+    fn named_holidays_round_trip_through_yaml() {
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let new_year = NaiveDate::from_ymd_opt(2018, 1, 1).unwrap();
+        let mut cal = Calendar::with_holidays(&[xmas, new_year]);
+        cal.working_days.remove(&Weekday::Fri);
+        cal.add_holiday_name(xmas, "Christmas Day");
+
+        let yaml = serde_yaml::to_string(&cal).unwrap();
+        let parsed: Calendar = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(
+            yaml,
+            "working_days:\n- Mon\n- Tue\n- Wed\n- Thu\n\
+             holidays:\n- 2017-12-25\n- 2018-01-01\n\
+             holiday_names:\n  2017-12-25: Christmas Day\n"
+        );
+        assert_eq!(parsed, cal);
+        assert_eq!(parsed.all_holidays_as_named(), cal.all_holidays_as_named());
+    }
+
+    #[test]
+    fn sequence_numbers_skip_holidays() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let reference = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let mon_before = NaiveDate::from_ymd_opt(2022, 9, 26).unwrap();
+
+        assert_eq!(
+            cal.business_day_sequence_number(reference, reference),
+            Some(0)
+        );
+        assert_eq!(cal.business_day_sequence_number(fri, reference), Some(1));
+        assert_eq!(cal.business_day_sequence_number(tue, reference), Some(2));
+        assert_eq!(
+            cal.business_day_sequence_number(mon_before, reference),
+            Some(-3)
+        );
+        assert_eq!(
+            cal.business_day_sequence_number(holiday_mon, reference),
+            None
+        );
+    }
+
+    #[test]
+    fn sequence_numbers_around_non_business_reference() {
+        let cal = Calendar::workweek();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        assert_eq!(cal.business_day_sequence_number(thu, sun), Some(-2));
+        assert_eq!(cal.business_day_sequence_number(tue, sun), Some(2));
+    }
+
+    #[test]
+    fn prev_month_same_weekday_stays_in_month() {
+        let cal = Calendar::workweek();
+
+        // Sat Oct 1st: the closest Saturday to Sep 1st is Sep 3rd, since Aug 27th is in August
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        assert_eq!(
+            cal.prev_month_same_weekday(sat),
+            NaiveDate::from_ymd_opt(2022, 9, 3).unwrap()
+        );
+
+        // Mon Oct 31st: September has no 31st, and the closest Monday to Sep 30th is Sep 26th
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 31).unwrap();
+        assert_eq!(
+            cal.prev_month_same_weekday(mon),
+            NaiveDate::from_ymd_opt(2022, 9, 26).unwrap()
+        );
+
+        // Tue Jan 3rd: the closest Tuesday to Dec 3rd is Dec 6th
+        let tue = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+        assert_eq!(
+            cal.prev_month_same_weekday(tue),
+            NaiveDate::from_ymd_opt(2022, 12, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn prev_month_same_business_position_clamps_to_month_end() {
+        let holidays: Vec<_> = (5..=30)
+            .map(|d| NaiveDate::from_ymd_opt(2022, 9, d).unwrap())
+            .collect();
+        let cal = Calendar::with_holidays(&holidays);
+
+        let oct_3rd_business = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let oct_1st_business = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+
+        assert_eq!(
+            cal.prev_month_same_business_position(oct_3rd_business),
+            Some(NaiveDate::from_ymd_opt(2022, 9, 2).unwrap())
+        );
+        assert_eq!(
+            cal.prev_month_same_business_position(oct_1st_business),
+            Some(NaiveDate::from_ymd_opt(2022, 9, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn unique_business_days_across_batches() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        let first: &[NaiveDate] = &[fri, holiday_mon, tue, tue];
+        let second: &[NaiveDate] = &[tue, wed];
+        let empty: &[NaiveDate] = &[];
+
+        assert_eq!(
+            cal.count_unique_business_days_across(&[first, second, empty]),
+            3
+        );
+        assert_eq!(cal.count_unique_business_days_across(&[]), 0);
+    }
+
+    #[test]
+    fn business_day_before_consecutive_holidays() {
+        let xmas = NaiveDate::from_ymd_opt(2017, 12, 25).unwrap();
+        let boxing_day = NaiveDate::from_ymd_opt(2017, 12, 26).unwrap();
+        let cal = Calendar::with_holidays(&[xmas, boxing_day]);
+
+        // Christmas 2017 is a Monday
+        let fri = NaiveDate::from_ymd_opt(2017, 12, 22).unwrap();
+
+        assert_eq!(cal.business_day_before_holiday(xmas), Some(fri));
+        assert_eq!(cal.business_day_before_holiday(boxing_day), Some(fri));
+    }
+
+    #[test]
+    fn business_day_before_weekend_holiday() {
+        let holiday_sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_sat, holiday_fri]);
+
+        let business_thu = NaiveDate::from_ymd_opt(2022, 9, 29).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+
+        assert_eq!(
+            cal.business_day_before_holiday(holiday_sat),
+            Some(business_thu)
+        );
+        assert_eq!(cal.business_day_before_holiday(sun), None);
+    }
+
+    #[test]
+    fn thu_fri_holidays_reopen_mon() {
+        let holiday_thu = NaiveDate::from_ymd_opt(2022, 10, 6).unwrap();
+        let holiday_fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_thu, holiday_fri]);
+
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+
+        assert_eq!(cal.next_business_day_after_holiday(holiday_thu), Some(mon));
+        assert_eq!(cal.next_business_day_after_holiday(holiday_fri), Some(mon));
+    }
+
+    #[test]
+    fn sun_holiday_reopens_mon() {
+        let holiday_sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_sun]);
+
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+
+        assert_eq!(cal.next_business_day_after_holiday(holiday_sun), Some(mon));
+        assert_eq!(cal.next_business_day_after_holiday(sat), None);
+    }
+
+    #[test]
+    fn last_registered_holiday_reopens_next_day() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 1, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 12, 27).unwrap(),
+        ]);
+
+        let holiday_tue = NaiveDate::from_ymd_opt(2022, 12, 27).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 12, 28).unwrap();
+
+        assert_eq!(cal.next_business_day_after_holiday(holiday_tue), Some(wed));
+    }
+
+    #[test]
+    fn business_days_between_excludes_end() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        assert_eq!(cal.business_days_between(fri, wed), 2);
+        assert_eq!(cal.business_days_between(fri, fri), 0);
+    }
+
+    #[test]
+    fn remaining_until_deadline_from_holiday() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let next_mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        assert_eq!(
+            cal.business_days_remaining_until(next_mon, holiday_mon),
+            Some(4)
+        );
+        assert_eq!(cal.business_days_remaining_until(tue, holiday_mon), Some(0));
+        assert_eq!(cal.business_days_remaining_until(tue, tue), None);
+    }
+
+    #[test]
+    fn remaining_until_today_for_past_target() {
+        let cal = Calendar::workweek();
+        let past = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+        assert_eq!(cal.business_days_remaining_until_today(past), None);
+    }
+
+    #[test]
+    fn settlement_lag_over_holiday() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+
+        assert!(cal.is_settlement_lag_valid(fri, wed, 2));
+        assert!(cal.is_settlement_lag_valid(fri, fri, 0));
+        assert!(!cal.is_settlement_lag_valid(fri, wed, 3));
+    }
+
+    #[test]
+    fn settlement_lag_rejects_non_business_and_backwards_dates() {
+        let cal = Calendar::workweek();
+
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        // add_business_days(sat, 1) is tue, but trades cannot happen on a weekend
+        assert!(!cal.is_settlement_lag_valid(sat, tue, 1));
+        assert!(!cal.is_settlement_lag_valid(mon, sat, 0));
+        assert!(!cal.is_settlement_lag_valid(tue, mon, 1));
+    }
+
+    #[test]
+    fn count_by_weekday_includes_all_working_days() {
+        let holiday_mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_mon]);
+
+        let mon = holiday_mon;
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let counts = cal.business_day_count_by_weekday(mon, wed);
+
+        let expected = HashMap::from([
+            (Weekday::Mon, 0),
+            (Weekday::Tue, 1),
+            (Weekday::Wed, 1),
+            (Weekday::Thu, 0),
+            (Weekday::Fri, 0),
+        ]);
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn holiday_first_of_month_is_skipped() {
+        let holiday_tue = NaiveDate::from_ymd_opt(2022, 11, 1).unwrap();
+        let cal = Calendar::with_holidays(&[holiday_tue]);
 
-        let business_thu = NaiveDate::from_ymd(2022, 09, 27);
+        let wed = NaiveDate::from_ymd_opt(2022, 11, 2).unwrap();
 
-        assert_eq!(cal.subtract_business_days(sun, 2), business_thu);
+        assert!(!cal.is_business_day_and_first_of_month(holiday_tue));
+        assert!(!cal.is_business_day_and_first_of_month(wed));
     }
 
     #[test]
-    fn wed_sub_2_business_is_mon() {
-        let wed = NaiveDate::from_ymd(2022, 10, 05);
+    fn last_of_month_in_leap_february() {
         let cal = Calendar::workweek();
 
-        let mon = NaiveDate::from_ymd(2022, 10, 03);
+        let thu = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let wed = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
 
-        assert_eq!(cal.subtract_business_days(wed, 2), mon);
+        assert!(cal.is_business_day_and_last_of_month(thu));
+        assert!(!cal.is_business_day_and_last_of_month(wed));
     }
 
     #[test]
-    fn parse_yaml() {
-        let input = "
-            working_days:
-                - monday
-                - tuesday
-                - friday
+    fn count_holidays_on_weekday_by_year() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2022, 12, 26).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 12, 27).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 8, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 12, 27).unwrap(),
+        ]);
 
-            holidays:
-              - 2022-01-01
-              - 2012-12-25
-        ";
-        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+        assert_eq!(cal.count_holidays_on_weekday(Weekday::Mon, Some(2022)), 2);
+        assert_eq!(cal.count_holidays_on_weekday(Weekday::Mon, None), 3);
+        assert_eq!(cal.count_holidays_on_weekday(Weekday::Tue, Some(2021)), 0);
+        assert_eq!(cal.count_holidays_on_weekday(Weekday::Sun, None), 0);
+    }
 
-        let expected = Calendar {
-            working_days: HashSet::from([Weekday::Mon, Weekday::Tue, Weekday::Fri]),
-            holidays: HashSet::from([
-                NaiveDate::from_ymd(2022, 1, 1),
-                NaiveDate::from_ymd(2012, 12, 25),
-            ]),
-        };
+    #[test]
+    fn business_days_in_month_excludes_holidays() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
 
-        assert_eq!(cal, expected);
+        assert_eq!(cal.business_days_in_month(2022, 12), 21);
     }
 
     #[test]
-    fn parse_yaml_with_defaults() {
-        let input = "
-            holidays:
-              - 2022-01-01
-              - 2012-12-25
-        ";
-        let cal: Calendar = serde_yaml::from_str(input).unwrap();
+    fn elapsed_business_fraction_is_one_at_month_end() {
+        let cal = Calendar::workweek();
+        let last_sat = NaiveDate::from_ymd_opt(2022, 4, 30).unwrap();
 
-        let expected = Calendar::with_holidays(&[
-            NaiveDate::from_ymd(2022, 1, 1),
-            NaiveDate::from_ymd(2012, 12, 25),
+        assert_eq!(cal.elapsed_business_fraction(last_sat), 1.0);
+    }
+
+    #[test]
+    fn elapsed_business_fraction_without_business_days_is_nan() {
+        let sundays = [7, 14, 21, 28].map(|day| NaiveDate::from_ymd_opt(2021, 2, day).unwrap());
+        let cal = Calendar::from_parts(
+            [Weekday::Sun].into_iter().collect(),
+            sundays.into_iter().collect(),
+        );
+
+        assert_eq!(cal.days_in_month_business_fraction(2021, 2), 0.0);
+        assert!(cal.elapsed_business_fraction(sundays[0]).is_nan());
+    }
+
+    #[test]
+    fn business_day_at_percentile_bounds_and_middle() {
+        let cal = Calendar::workweek();
+        let middle = cal.business_day_at_percentile(2024, 0.5);
+
+        assert_eq!(
+            cal.business_day_at_percentile(2024, 0.0),
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+        assert_eq!(cal.business_day_of_year_index(middle), Some(131));
+        assert_eq!(middle, NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+        assert_eq!(
+            cal.business_day_at_percentile(2024, 1.0),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn business_day_at_percentile_above_1_panics() {
+        Calendar::workweek().business_day_at_percentile(2024, 1.5);
+    }
+
+    #[test]
+    fn merge_is_business_day_in_both() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let mut six_day = Calendar::with_holidays(&[tue]);
+        six_day.working_days.insert(Weekday::Sat);
+        let mut weekend = Calendar::with_holidays(&[sat]);
+        weekend.working_days = [Weekday::Sat, Weekday::Sun, Weekday::Mon]
+            .into_iter()
+            .collect();
+
+        let merged = six_day.merge(&weekend).unwrap();
+
+        assert_eq!(
+            merged.working_days,
+            [Weekday::Sat, Weekday::Mon].into_iter().collect()
+        );
+        assert_eq!(merged.holidays, [sat, tue].into_iter().collect());
+        assert!(!merged.is_business_day(sat));
+        assert!(!merged.is_business_day(sun));
+        assert!(merged.is_business_day(mon));
+        assert!(!merged.is_business_day(tue));
+    }
+
+    #[test]
+    fn merge_without_common_working_days_is_error() {
+        let mut weekend = Calendar::workweek();
+        weekend.working_days = [Weekday::Sat, Weekday::Sun].into_iter().collect();
+
+        let result = Calendar::workweek().merge(&weekend);
+
+        assert_eq!(result, Err(CalendarError::NoWorkingDays));
+    }
+
+    #[test]
+    fn merge_prefer_open_is_business_day_in_either() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let mut weekend = Calendar::with_holidays(&[sat, mon]);
+        weekend.working_days = [Weekday::Sat, Weekday::Sun].into_iter().collect();
+        weekend.add_holiday_name(sat, "Weekend Off");
+        weekend.add_holiday_name(mon, "Ignored");
+        let mut cal = Calendar::with_holidays(&[mon]);
+        cal.add_holiday_name(mon, "Bank Holiday");
+
+        let merged = cal.merge_prefer_open(&weekend);
+
+        assert_eq!(merged.working_days.len(), 7);
+        assert_eq!(merged.holidays, [sat, mon].into_iter().collect());
+        assert!(merged.is_business_day(sun));
+        assert!(!merged.is_business_day(mon));
+        assert_eq!(merged.holiday_name(mon).unwrap(), "Bank Holiday");
+        assert_eq!(merged.holiday_name(sat).unwrap(), "Weekend Off");
+    }
+
+    #[test]
+    fn month_fraction_rounds_to_nearest_day() {
+        let cal = Calendar::workweek();
+
+        // 15.5 rounds to Sunday the 16th
+        assert_eq!(
+            cal.business_day_nearest_to_month_fraction(2022, 10, 0.5),
+            NaiveDate::from_ymd_opt(2022, 10, 17).unwrap()
+        );
+        // Saturday the 1st is nearest to the last business day of September
+        assert_eq!(
+            cal.business_day_nearest_to_month_fraction(2022, 10, 0.0),
+            NaiveDate::from_ymd_opt(2022, 9, 30).unwrap()
+        );
+        assert_eq!(
+            cal.business_day_nearest_to_month_fraction(2022, 10, 1.0),
+            NaiveDate::from_ymd_opt(2022, 10, 31).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_month_fraction_panics() {
+        Calendar::workweek().business_day_nearest_to_month_fraction(2022, 10, -0.1);
+    }
+
+    #[test]
+    fn overlap_with_different_working_days() {
+        let mut sun_to_thu = Calendar::workweek();
+        sun_to_thu.working_days = [
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+        ]
+        .into_iter()
+        .collect();
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 4).unwrap()]);
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 8).unwrap();
+
+        assert_eq!(cal.overlap_with(&sun_to_thu, sun, sat), 3);
+        assert_eq!(cal.overlap_with(&sun_to_thu, sat, sun), 0);
+    }
+
+    #[test]
+    fn stretch_to_business_week_with_monday_holiday() {
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+        let fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+        let cal = Calendar::with_holidays(&[mon]);
+
+        assert_eq!(cal.stretch_to_business_week(mon), Some((tue, fri)));
+    }
+
+    #[test]
+    fn stretch_to_business_week_with_single_or_no_business_day() {
+        let days = [3, 4, 5, 6, 7].map(|day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap());
+        let thu = days[3];
+        let cal = Calendar::with_holidays(&[days[0], days[1], days[2], days[4]]);
+
+        assert_eq!(cal.stretch_to_business_week(thu), Some((thu, thu)));
+        assert_eq!(
+            Calendar::with_holidays(&days).stretch_to_business_week(thu),
+            None
+        );
+    }
+
+    #[test]
+    fn business_day_hash_map_covers_every_calendar_day() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()]);
+        let start = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+
+        let map = cal.business_day_hash_map_for_range(start, end);
+
+        assert_eq!(map.len(), 62);
+        assert_eq!(map.values().filter(|&&is_business| is_business).count(), 43);
+        assert!(map
+            .iter()
+            .all(|(&date, &is_business)| is_business == cal.is_business_day(date)));
+    }
+
+    #[test]
+    fn days_by_month_split_holidays_from_business_days() {
+        let boxing_day = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+        let cal = Calendar::with_holidays(&[boxing_day]);
+
+        let business = cal.business_day_list_by_month(2022);
+        let non_business = cal.non_business_day_list_by_month(2022);
+
+        assert_eq!(business[&12].len(), 21);
+        assert_eq!(
+            business[&12][0],
+            NaiveDate::from_ymd_opt(2022, 12, 1).unwrap()
+        );
+        assert!(!business[&12].contains(&boxing_day));
+        assert_eq!(non_business[&12].len(), 10);
+        assert!(non_business[&12].contains(&boxing_day));
+        assert!(non_business[&12].windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn fence_around_holiday_skips_weekend() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let xmas_eve = NaiveDate::from_ymd_opt(2020, 12, 24).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+
+        assert_eq!(cal.business_day_fence(xmas), (xmas_eve, mon));
+        assert_eq!(
+            cal.business_day_fence(xmas),
+            (cal.roll_backward(xmas), cal.roll_forward(xmas))
+        );
+    }
+
+    #[test]
+    fn working_weekdays_in_leap_year_starting_on_saturday() {
+        let mut cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()]);
+        cal.working_days = [Weekday::Sat].into_iter().collect();
+
+        // 2000-01-01 and 2000-12-30 are both Saturdays
+        assert_eq!(cal.count_working_weekdays_without_holidays(2000), 53);
+        assert_eq!(cal.business_days_in_year(2000), 52);
+    }
+
+    #[test]
+    fn prefix_sum_matches_range_counts() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
         ]);
+        let prefix = cal.business_day_count_prefix_sum(2024);
+        let start = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
 
-        assert_eq!(cal, expected);
+        for end in [
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+        ] {
+            assert_eq!(
+                prefix[end.ordinal() as usize] - prefix[start.ordinal0() as usize],
+                cal.count_business_days_inclusive(start, end)
+            );
+        }
+        assert_eq!(prefix[366], cal.business_days_in_year(2024));
+    }
+
+    #[test]
+    fn holiday_stretch_does_not_cross_year_end() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2021, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 1, 3).unwrap(),
+        ]);
+
+        // Fri 31st, then Sat 1st to Mon 3rd
+        assert!(!cal.contains_long_holiday_stretch(2021, 3));
+        assert!(cal.contains_long_holiday_stretch(2022, 3));
+        assert!(!cal.contains_long_holiday_stretch(2022, 4));
+        assert!(cal.contains_long_holiday_stretch(2022, 0));
+    }
+
+    #[test]
+    fn month_end_target_can_fall_in_previous_month() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 8, 31).unwrap()]);
+        let date = NaiveDate::from_ymd_opt(2022, 8, 15).unwrap();
+
+        assert_eq!(
+            cal.business_days_before_month_end_target(date, 0),
+            NaiveDate::from_ymd_opt(2022, 8, 30).unwrap()
+        );
+        assert_eq!(
+            cal.business_days_before_month_end_target(date, 22),
+            NaiveDate::from_ymd_opt(2022, 7, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn non_holiday_periods_are_clipped_to_range() {
+        let cal = Calendar::workweek();
+        let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+        let fri = NaiveDate::from_ymd_opt(2022, 10, 7).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 8).unwrap();
+
+        assert_eq!(
+            cal.intersect_with_non_holiday_period(wed, mon),
+            vec![(wed, fri), (mon, mon)]
+        );
+        assert!(cal.intersect_with_non_holiday_period(sat, sat).is_empty());
+        assert!(cal.intersect_with_non_holiday_period(mon, wed).is_empty());
+    }
+
+    #[test]
+    fn settlement_window_over_holiday() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let trade_date = NaiveDate::from_ymd_opt(2020, 12, 22).unwrap();
+
+        let window = cal.settlement_window(trade_date, 1, 5);
+
+        assert_eq!(window.len(), 5);
+        assert!(window.iter().all(|&date| cal.is_business_day(date)));
+        assert_eq!(window[0], NaiveDate::from_ymd_opt(2020, 12, 23).unwrap());
+        assert_eq!(window[4], cal.add_business_days(trade_date, 5));
+        assert!(cal.settlement_window(trade_date, 3, 2).is_empty());
+    }
+
+    #[test]
+    fn business_days_per_month_in_leap_year() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 24).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+        ]);
+
+        let per_month = cal.business_days_per_month(2024);
+        let per_quarter = cal.business_days_per_quarter(2024);
+
+        assert_eq!(per_month[1], (2, 20));
+        assert_eq!(per_month[11], (12, 19));
+        assert_eq!(per_quarter, [(1, 64), (2, 65), (3, 66), (4, 63)]);
+        assert_eq!(
+            per_quarter.iter().map(|(_, count)| count).sum::<u32>(),
+            cal.business_days_in_year(2024)
+        );
+    }
+
+    #[test]
+    fn next_period_start_skips_holidays() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 12, 28).unwrap(),
+        ]);
+        let date = NaiveDate::from_ymd_opt(2020, 12, 23).unwrap();
+
+        assert_eq!(
+            cal.advance_to_next_period_start(date, PeriodType::Week),
+            NaiveDate::from_ymd_opt(2020, 12, 29).unwrap()
+        );
+        for period in [PeriodType::Month, PeriodType::Quarter, PeriodType::Year] {
+            assert_eq!(
+                cal.advance_to_next_period_start(date, period),
+                NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(),
+                "{:?}",
+                period
+            );
+        }
+    }
+
+    #[test]
+    fn year_fraction_increases_through_the_year() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+        ]);
+
+        let fractions: Vec<_> = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .iter_days()
+            .take_while(|date| date.year() == 2024)
+            .filter_map(|date| cal.business_day_of_year_fraction(date))
+            .collect();
+
+        assert_eq!(fractions.len(), 260);
+        assert_eq!(fractions[0], 0.0);
+        assert_eq!(fractions[259], 1.0);
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn month_range_skips_holidays_and_handles_empty_ranges() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 3).unwrap()]);
+
+        assert_eq!(
+            cal.business_day_within_month_range(2022, 10, 0, 1),
+            vec![NaiveDate::from_ymd_opt(2022, 10, 4).unwrap()]
+        );
+        assert!(cal
+            .business_day_within_month_range(2022, 10, 3, 2)
+            .is_empty());
+        assert!(cal
+            .business_day_within_month_range(2022, 10, 25, 30)
+            .is_empty());
+    }
+
+    #[test]
+    fn grid_alignment_keeps_first_occurrence_order() {
+        let cal = Calendar::workweek();
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 10, 2).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let dates = [mon, sat, fri, sun];
+
+        assert_eq!(
+            cal.align_to_business_day_grid(&dates, RollingConvention::Following, false),
+            vec![mon, fri]
+        );
+        assert_eq!(
+            cal.align_to_business_day_grid(&dates, RollingConvention::Preceding, false),
+            vec![mon, fri]
+        );
+    }
+
+    #[test]
+    fn period_overlap_excludes_holidays() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 10, 11).unwrap()]);
+        let ymd = |day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap();
+
+        assert_eq!(
+            cal.period_business_day_overlap((ymd(10), ymd(31)), (ymd(1), ymd(12))),
+            2
+        );
+        assert_eq!(
+            cal.period_business_day_overlap((ymd(12), ymd(12)), (ymd(1), ymd(31))),
+            1
+        );
+    }
+
+    #[test]
+    fn gap_analysis_cuts_runs_at_year_edges() {
+        let cal = Calendar::workweek();
+        let jan_1 = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let jan_2 = NaiveDate::from_ymd_opt(2022, 1, 2).unwrap();
+        let dec_31 = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+
+        let gaps = cal.holiday_gap_analysis(2022, 0);
+
+        assert_eq!(gaps.first(), Some(&(jan_1, jan_2, 2)));
+        assert_eq!(gaps.last(), Some(&(dec_31, dec_31, 1)));
+        assert!(cal.holiday_gap_analysis(2022, 3).is_empty());
+    }
+
+    #[test]
+    fn leap_day_anniversary_falls_on_feb_28() {
+        let cal = Calendar::workweek();
+        let start = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+
+        // 28th February 2021 is a Sunday, so it rolls back within the month
+        assert_eq!(
+            cal.next_business_anniversary(start, start),
+            NaiveDate::from_ymd_opt(2021, 2, 26).unwrap()
+        );
+        assert_eq!(
+            cal.next_business_anniversary(start, NaiveDate::from_ymd_opt(2023, 3, 1).unwrap()),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn merge_keeps_names_from_both_calendars() {
+        let xmas = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+        let mut cal = Calendar::with_holidays(&[xmas]);
+        cal.add_holiday_name(xmas, "Christmas Day");
+        let mut other = Calendar::with_holidays(&[xmas]);
+        other.add_holiday_name(xmas, "Christmas Day");
+        other.add_holiday_name(xmas, "Feast of the Nativity");
+
+        let merged = cal.merge(&other).unwrap();
+
+        assert!(merged.is_double_holiday(xmas));
+        assert_eq!(
+            merged.holiday_names_on(xmas),
+            vec!["Christmas Day", "Feast of the Nativity"]
+        );
+        assert!(!cal.is_double_holiday(xmas));
+        assert!(!merged.is_double_holiday(xmas + Duration::days(1)));
+    }
+
+    #[test]
+    fn upcoming_and_past_holidays_are_limited_by_count() {
+        let holidays: Vec<_> = (1..=5)
+            .map(|month| NaiveDate::from_ymd_opt(2022, month, 10).unwrap())
+            .collect();
+        let cal = Calendar::with_holidays(&holidays);
+
+        assert_eq!(cal.upcoming_holidays(holidays[1], 2), holidays[2..4]);
+        assert_eq!(
+            cal.past_holidays(holidays[3], 5),
+            vec![holidays[2], holidays[1], holidays[0]]
+        );
+        assert_eq!(
+            cal.past_holidays(holidays[4], 2),
+            vec![holidays[3], holidays[2]]
+        );
+        assert!(cal.upcoming_holidays(holidays[0], 0).is_empty());
+        assert!(cal.upcoming_holidays(holidays[4], 3).is_empty());
+        assert!(cal.past_holidays(holidays[0], 3).is_empty());
+    }
+
+    #[test]
+    fn count_business_days_matching_skips_non_business_days() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2022, 8, 15).unwrap()]);
+        let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+
+        // The 15th is a business day in every month but January, May and October, which fall on
+        // weekends, and August, which is a holiday
+        assert_eq!(
+            cal.count_business_days_matching(start, end, |date| date.day() == 15),
+            8
+        );
+        assert_eq!(
+            cal.count_business_days_matching(start, end, |_| true),
+            cal.business_days_in_year(2022)
+        );
+        assert_eq!(cal.count_business_days_matching(end, start, |_| true), 0);
+    }
+
+    #[test]
+    fn epoch_offsets_round_trip_around_holidays() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        // Saturday, so offsets on either side start from the adjacent business days
+        let reference = NaiveDate::from_ymd_opt(2020, 12, 19).unwrap();
+
+        for offset in -10..=10 {
+            let date = cal.date_at_epoch_offset(reference, offset);
+            let expected = if offset == 0 { None } else { Some(offset) };
+            assert_eq!(cal.business_day_epoch_offset(date, reference), expected);
+        }
+        assert_eq!(cal.business_day_epoch_offset(xmas, reference), None);
+        assert_eq!(
+            cal.date_at_epoch_offset(reference, 5),
+            NaiveDate::from_ymd_opt(2020, 12, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn merge_with_priority_ignores_low_working_days() {
+        let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let mut high = Calendar::with_holidays(&[mon]);
+        high.add_holiday_name(mon, "Bank Holiday");
+        let mut low = Calendar::with_holidays(&[mon]);
+        low.working_days.insert(Weekday::Sat);
+        low.add_holiday_name(mon, "Local Holiday");
+
+        let merged = Calendar::merge_with_priority(high, low);
+
+        assert_eq!(merged.working_days, workweek());
+        assert!(!merged.is_business_day(sat));
+        assert_eq!(
+            merged.holiday_names_on(mon),
+            vec!["Bank Holiday", "Local Holiday"]
+        );
+    }
+
+    #[test]
+    fn fiscal_year_boundaries_skip_holidays() {
+        let new_year = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let cal = Calendar::with_holidays(&[new_year]);
+        let dec_29 = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+        let dec_31 = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let jan_2 = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        assert!(cal.is_fiscal_year_end(dec_29, 1));
+        assert!(!cal.is_fiscal_year_end(dec_31, 1));
+        assert!(!cal.is_fiscal_year_start(new_year, 1));
+        assert!(cal.is_fiscal_year_start(jan_2, 1));
+        assert!(!cal.is_fiscal_year_start(jan_2, 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fiscal_year_start_month_out_of_range_panics() {
+        Calendar::workweek().is_fiscal_year_end(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), 13);
+    }
+
+    #[test]
+    fn polars_series_covers_leap_year() {
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let cal = Calendar::with_holidays(&[leap_day]);
+
+        let (ordinals, is_business) = cal.to_polars_series(2024);
+
+        assert_eq!(ordinals.len(), 366);
+        assert_eq!(ordinals.last(), Some(&366));
+        assert!(!is_business[leap_day.ordinal0() as usize]);
+        assert_eq!(
+            is_business.iter().filter(|&&b| b).count() as u32,
+            cal.business_days_in_year(2024)
+        );
+    }
+
+    #[test]
+    fn standardize_holidays_handles_leap_days() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        ]);
+
+        let standardized = cal.standardize_holidays_for_year(2024, 2023);
+        assert_eq!(
+            standardized,
+            vec![NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()]
+        );
+        assert!(standardized.iter().all(|date| date.year() == 2023));
+        assert_eq!(
+            cal.standardize_holidays_for_year(2024, 2028),
+            vec![
+                NaiveDate::from_ymd_opt(2028, 2, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2028, 2, 29).unwrap(),
+            ]
+        );
+        assert!(cal.standardize_holidays_for_year(2025, 2026).is_empty());
+    }
+
+    #[test]
+    fn optimistic_count_is_upper_bound() {
+        let xmas = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+        let mut cal = Calendar::with_holidays(&[xmas, xmas + Duration::days(1)]);
+        cal.working_days.insert(Weekday::Sun);
+        let start = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+
+        for days in 0..40 {
+            let end = start + Duration::days(days);
+            let naive = start
+                .iter_days()
+                .take_while(|date| *date < end)
+                .filter(|date| cal.working_days.contains(&date.weekday()))
+                .count() as u32;
+            assert_eq!(cal.business_day_count_optimistic(start, end), naive);
+            assert!(cal.business_days_between(start, end) <= naive);
+        }
+        assert_eq!(
+            cal.business_day_count_optimistic(start, start - Duration::days(3)),
+            0
+        );
+    }
+
+    #[test]
+    fn earliest_common_business_day_skips_each_calendars_holidays() {
+        let thu = NaiveDate::from_ymd_opt(2022, 12, 22).unwrap();
+        let fri = NaiveDate::from_ymd_opt(2022, 12, 23).unwrap();
+        let mon = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 12, 27).unwrap();
+        let uk = Calendar::with_holidays(&[mon, tue]);
+        let mut us = Calendar::with_holidays(&[fri, mon]);
+        us.working_days.insert(Weekday::Sat);
+
+        assert_eq!(uk.find_earliest_common_business_day(&[thu, fri]), fri);
+        assert_eq!(
+            Calendar::find_earliest_common_business_day_multi(&[thu, fri], &[&uk, &us]),
+            tue + Duration::days(1)
+        );
+        assert_eq!(
+            Calendar::find_earliest_common_business_day_multi(&[mon], &[]),
+            mon
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn earliest_common_business_day_without_shared_working_days_panics() {
+        let weekdays = Calendar::workweek();
+        let mut weekend = Calendar::workweek();
+        weekend.working_days = [Weekday::Sat, Weekday::Sun].into_iter().collect();
+        let date = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+
+        Calendar::find_earliest_common_business_day_multi(&[date], &[&weekdays, &weekend]);
+    }
+
+    #[test]
+    fn interpolation_hits_both_ends() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let start = NaiveDate::from_ymd_opt(2020, 12, 24).unwrap();
+        let end = NaiveDate::from_ymd_opt(2020, 12, 28).unwrap();
+
+        assert_eq!(
+            cal.interpolate_business_value(start, 3.0, end, 5.0, start),
+            Some(3.0)
+        );
+        assert_eq!(
+            cal.interpolate_business_value(start, 3.0, end, 5.0, end),
+            Some(5.0)
+        );
+        assert_eq!(
+            cal.interpolate_business_value(start, 3.0, end, 5.0, xmas),
+            Some(5.0)
+        );
+        assert_eq!(
+            cal.interpolate_business_value(xmas, 3.0, xmas, 5.0, xmas),
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn business_day_batch_matches_single_lookups() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+        ]);
+        let dates: Vec<_> = NaiveDate::from_ymd_opt(2023, 12, 1)
+            .unwrap()
+            .iter_days()
+            .step_by(3)
+            .take(150)
+            .collect();
+        let mut reversed = dates.clone();
+        reversed.reverse();
+
+        let expected: Vec<_> = dates
+            .iter()
+            .map(|&date| cal.is_business_day(date))
+            .collect();
+        assert_eq!(cal.is_business_day_batch(&dates), expected);
+        assert_eq!(
+            cal.is_business_day_batch(&reversed),
+            expected.into_iter().rev().collect::<Vec<_>>()
+        );
+        assert!(cal.is_business_day_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn business_day_batch_mixes_dense_and_sparse_years() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2030, 1, 1).unwrap(),
+        ]);
+        let mut dates: Vec<_> = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .iter_days()
+            .take(366)
+            .collect();
+        dates.push(NaiveDate::from_ymd_opt(2030, 1, 1).unwrap());
+        dates.push(NaiveDate::from_ymd_opt(2030, 1, 2).unwrap());
+
+        let expected: Vec<_> = dates
+            .iter()
+            .map(|&date| cal.is_business_day(date))
+            .collect();
+        assert_eq!(cal.is_business_day_batch(&dates), expected);
+    }
+
+    #[test]
+    fn business_fridays_in_2024_exclude_holiday_fridays() {
+        let holidays = [(3, 29), (12, 25), (12, 26)]
+            .map(|(month, day)| NaiveDate::from_ymd_opt(2024, month, day).unwrap());
+        let cal = Calendar::with_holidays(&holidays);
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        let fridays = cal.generate_weekly_business_days(start, end, Weekday::Fri);
+        let all_fridays = start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .filter(|date| date.weekday() == Weekday::Fri)
+            .count();
+        let holiday_fridays = holidays
+            .iter()
+            .filter(|date| date.weekday() == Weekday::Fri)
+            .count();
+
+        assert_eq!(fridays.len(), all_fridays - holiday_fridays);
+        assert_eq!(fridays.len(), 51);
+        assert!(fridays.iter().all(|date| date.weekday() == Weekday::Fri));
+        assert!(cal
+            .generate_weekly_business_days(start, end, Weekday::Sat)
+            .is_empty());
+    }
+
+    #[test]
+    fn iso_week_map_keeps_weeks_without_business_days() {
+        let mut holidays = Vec::new();
+        for day in 26..=30 {
+            holidays.push(NaiveDate::from_ymd_opt(2022, 12, day).unwrap());
+        }
+        let cal = Calendar::with_holidays(&holidays);
+
+        let by_week = cal.business_day_iso_week_map(2022);
+
+        assert!(by_week[&(2022, 52)].is_empty());
+        assert_eq!(
+            by_week.values().map(Vec::len).sum::<usize>() as u32,
+            cal.business_days_in_year(2022)
+        );
+        assert!(by_week
+            .values()
+            .all(|dates| dates.windows(2).all(|pair| pair[0] < pair[1])));
+    }
+
+    #[test]
+    fn countdown_matches_remaining_business_days() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let target = NaiveDate::from_ymd_opt(2020, 12, 30).unwrap();
+
+        let countdown = cal.business_day_countdown_vec(target, 20);
+
+        assert_eq!(countdown.len(), 21);
+        assert_eq!(countdown[0].0, target - Duration::days(20));
+        assert_eq!(countdown[20], (target, 0));
+        for &(date, count) in &countdown[..20] {
+            assert_eq!(
+                cal.business_days_remaining_until(target, date),
+                Some(count),
+                "{}",
+                date
+            );
+        }
+        assert_eq!(cal.business_day_countdown_vec(target, 0), vec![(target, 0)]);
+    }
+
+    #[test]
+    fn next_business_anniversary_skips_anniversary_rolled_back_before_from() {
+        let cal = Calendar::workweek();
+        let start = NaiveDate::from_ymd_opt(2020, 10, 31).unwrap();
+        // 31st October 2021 is a Sunday, so the anniversary rolls back to Friday the 29th
+        let from = NaiveDate::from_ymd_opt(2021, 10, 30).unwrap();
+
+        assert_eq!(
+            cal.next_business_anniversary(start, from),
+            NaiveDate::from_ymd_opt(2022, 10, 31).unwrap()
+        );
+    }
+
+    #[test]
+    fn iso_week_map_keeps_both_weeks_numbered_1() {
+        let cal = Calendar::workweek();
+
+        let by_week = cal.business_day_iso_week_map(2024);
+
+        // Monday the 1st of January 2024 starts week 1 of 2024, and Monday the 30th of December
+        // starts week 1 of 2025
+        assert_eq!(by_week.len(), 53);
+        assert_eq!(by_week[&(2024, 1)].len(), 5);
+        assert_eq!(
+            by_week[&(2025, 1)],
+            vec![
+                NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            ]
+        );
     }
 }
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Calendar;
+
+impl Calendar {
+    /// Loads every `.yml` and `.yaml` file in the directory as a calendar (see
+    /// [Building a `Calendar` from YAML](crate#building-a-calendar-from-yaml)), paired with the
+    /// file name without its extension. Calendars are returned sorted by name. Subdirectories are
+    /// not searched.
+    ///
+    /// # Errors
+    ///
+    /// If any file cannot be read or parsed, returns a [`DirectoryLoadError`] listing every
+    /// failure, rather than stopping at the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// # use business::Calendar;
+    /// let calendars = Calendar::load_from_directory(Path::new("examples/basic")).unwrap();
+    /// assert_eq!(calendars.len(), 1);
+    /// assert_eq!(calendars[0].0, "cal");
+    /// ```
+    pub fn load_from_directory(dir: &Path) -> Result<Vec<(String, Calendar)>, DirectoryLoadError> {
+        let mut calendars = Vec::new();
+        let mut errors = Vec::new();
+
+        let entries = fs::read_dir(dir).map_err(|err| DirectoryLoadError {
+            errors: vec![(dir.to_path_buf(), LoadError::Io(err))],
+        })?;
+
+        for entry in entries {
+            let path = match entry {
+                Ok(entry) => entry.path(),
+                Err(err) => {
+                    errors.push((dir.to_path_buf(), LoadError::Io(err)));
+                    continue;
+                }
+            };
+
+            let is_yaml = matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("yml" | "yaml")
+            );
+            if !is_yaml || !path.is_file() {
+                continue;
+            }
+
+            match load_file(&path) {
+                Ok(cal) => {
+                    let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                    calendars.push((name, cal));
+                }
+                Err(err) => errors.push((path, err)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(DirectoryLoadError { errors });
+        }
+
+        calendars.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(calendars)
+    }
+}
+
+fn load_file(path: &Path) -> Result<Calendar, LoadError> {
+    let yml = fs::read_to_string(path).map_err(LoadError::Io)?;
+    serde_yaml::from_str(&yml).map_err(LoadError::Yaml)
+}
+
+/// Error loading a single calendar file.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read
+    Io(io::Error),
+    /// The file is not a valid calendar
+    Yaml(serde_yaml::Error),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "could not read calendar: {}", err),
+            LoadError::Yaml(err) => write!(f, "could not parse calendar: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(err) => Some(err),
+            LoadError::Yaml(err) => Some(err),
+        }
+    }
+}
+
+/// Error returned by [`Calendar::load_from_directory`], with the failure for each file that could
+/// not be loaded.
+#[derive(Debug)]
+pub struct DirectoryLoadError {
+    /// The path of each file that failed to load, along with why
+    pub errors: Vec<(PathBuf, LoadError)>,
+}
+
+impl fmt::Display for DirectoryLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to load {} calendar(s)", self.errors.len())?;
+        for (path, err) in &self.errors {
+            write!(f, "\n{}: {}", path.display(), err)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DirectoryLoadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("business-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn loads_yml_and_yaml_files_sorted_by_name() {
+        let dir = test_dir("load-sorted");
+        fs::write(dir.join("uk.yml"), "holidays:\n  - 2017-12-25\n").unwrap();
+        fs::write(dir.join("de.yaml"), "holidays:\n  - 2017-10-03\n").unwrap();
+        fs::write(dir.join("notes.txt"), "not a calendar").unwrap();
+        fs::create_dir(dir.join("nested.yml")).unwrap();
+
+        let calendars = Calendar::load_from_directory(&dir).unwrap();
+
+        let expected = vec![
+            (
+                "de".to_string(),
+                Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2017, 10, 3).unwrap()]),
+            ),
+            (
+                "uk".to_string(),
+                Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2017, 12, 25).unwrap()]),
+            ),
+        ];
+        assert_eq!(calendars, expected);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn collects_errors_from_every_bad_file() {
+        let dir = test_dir("load-errors");
+        fs::write(dir.join("good.yml"), "holidays: []\n").unwrap();
+        fs::write(dir.join("bad_date.yml"), "holidays:\n  - 2017-13-01\n").unwrap();
+        fs::write(
+            dir.join("bad_day.yaml"),
+            "working_days: [funday]\nholidays: []\n",
+        )
+        .unwrap();
+
+        let err = Calendar::load_from_directory(&dir).unwrap_err();
+
+        let mut failed: Vec<_> = err
+            .errors
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        failed.sort_unstable();
+        assert_eq!(failed, vec!["bad_date.yml", "bad_day.yaml"]);
+        assert!(err
+            .errors
+            .iter()
+            .all(|(_, err)| matches!(err, LoadError::Yaml(_))));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_directory_is_error() {
+        let dir = std::env::temp_dir().join("business-does-not-exist");
+
+        let err = Calendar::load_from_directory(&dir).unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert!(matches!(err.errors[0].1, LoadError::Io(_)));
+    }
+}
@@ -0,0 +1,106 @@
+/// Builds a [`Calendar`](crate::Calendar) inline from weekday names and ISO 8601 date literals.
+///
+/// Weekdays can be given by their full name (`Monday`) or three-letter abbreviation (`Mon`).
+///
+/// ```
+/// use business::calendar;
+/// use chrono::NaiveDate;
+///
+/// let cal = calendar!(
+///     working_days: [Mon, Tue, Wed, Thursday, Friday],
+///     holidays: [2024-12-25, 2024-12-26],
+/// );
+///
+/// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()), false);
+/// assert_eq!(cal.is_business_day(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap()), true);
+/// ```
+///
+/// Dates are checked at compile time, so an invalid date fails to build:
+///
+/// ```compile_fail
+/// let cal = business::calendar!(working_days: [Mon], holidays: [2023-02-29]);
+/// ```
+///
+/// A calendar needs at least one working day, so an empty list fails to build too:
+///
+/// ```compile_fail
+/// let cal = business::calendar!(working_days: [], holidays: []);
+/// ```
+#[macro_export]
+macro_rules! calendar {
+    (
+        working_days: [$($day:ident),+ $(,)?],
+        holidays: [$($year:literal - $month:literal - $dom:literal),* $(,)?] $(,)?
+    ) => {
+        {
+            let mut cal = $crate::Calendar::workweek();
+            cal.working_days = ::std::collections::HashSet::from([$($crate::__weekday!($day)),*]);
+            cal.holidays = ::std::collections::HashSet::from([
+                $($crate::__date!($year, $month, $dom)),*
+            ]);
+            cal
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __weekday {
+    (Mon) => {
+        $crate::__chrono::Weekday::Mon
+    };
+    (Monday) => {
+        $crate::__chrono::Weekday::Mon
+    };
+    (Tue) => {
+        $crate::__chrono::Weekday::Tue
+    };
+    (Tuesday) => {
+        $crate::__chrono::Weekday::Tue
+    };
+    (Wed) => {
+        $crate::__chrono::Weekday::Wed
+    };
+    (Wednesday) => {
+        $crate::__chrono::Weekday::Wed
+    };
+    (Thu) => {
+        $crate::__chrono::Weekday::Thu
+    };
+    (Thursday) => {
+        $crate::__chrono::Weekday::Thu
+    };
+    (Fri) => {
+        $crate::__chrono::Weekday::Fri
+    };
+    (Friday) => {
+        $crate::__chrono::Weekday::Fri
+    };
+    (Sat) => {
+        $crate::__chrono::Weekday::Sat
+    };
+    (Saturday) => {
+        $crate::__chrono::Weekday::Sat
+    };
+    (Sun) => {
+        $crate::__chrono::Weekday::Sun
+    };
+    (Sunday) => {
+        $crate::__chrono::Weekday::Sun
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __date {
+    ($year:literal, $month:literal, $dom:literal) => {{
+        // Zero-padded months and days are expected in ISO 8601 dates.
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATE: $crate::__chrono::NaiveDate =
+            match $crate::__chrono::NaiveDate::from_ymd_opt($year, $month, $dom) {
+                Some(date) => date,
+                None => panic!(concat!("invalid date: ", stringify!($year - $month - $dom))),
+            };
+        DATE
+    }};
+}
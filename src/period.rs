@@ -0,0 +1,79 @@
+use chrono::{naive::NaiveDate, Duration};
+
+use crate::Calendar;
+
+/// A kind of calendar period, as used by
+/// [`Calendar::advance_to_next_period_start`](crate::Calendar::advance_to_next_period_start).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeriodType {
+    /// An ISO week, starting on Monday
+    Week,
+    /// A calendar month
+    Month,
+    /// A calendar quarter, starting in January, April, July or October
+    Quarter,
+    /// A calendar year
+    Year,
+}
+
+/// A range of dates, inclusive of both ends, to be inspected with a particular [`Calendar`].
+///
+/// Usually created through [`Calendar::for_month`], [`Calendar::for_quarter`] or
+/// [`Calendar::for_year_period`].
+///
+/// ```
+/// # use chrono::NaiveDate;
+/// # use business::Calendar;
+/// let cal = Calendar::workweek();
+/// let oct = cal.for_month(2022, 10);
+///
+/// assert_eq!(oct.start(), NaiveDate::from_ymd_opt(2022, 10, 1).unwrap());
+/// assert_eq!(oct.end(), NaiveDate::from_ymd_opt(2022, 10, 31).unwrap());
+/// assert_eq!(oct.business_days().len(), 21);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarPeriod<'a> {
+    calendar: &'a Calendar,
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl<'a> CalendarPeriod<'a> {
+    /// Creates a period spanning `start` to `end`, both inclusive. If `end` is before `start`,
+    /// the period is empty.
+    pub fn new(calendar: &'a Calendar, start: NaiveDate, end: NaiveDate) -> CalendarPeriod<'a> {
+        Self {
+            calendar,
+            start,
+            end,
+        }
+    }
+
+    /// The first date of the period.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// The last date of the period.
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns `true` if the date falls within the period.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Returns all business days in the period, in chronological order.
+    pub fn business_days(&self) -> Vec<NaiveDate> {
+        let mut result = Vec::new();
+        let mut date = self.start;
+        while date <= self.end {
+            if self.calendar.is_business_day(date) {
+                result.push(date);
+            }
+            date += Duration::days(1);
+        }
+        result
+    }
+}
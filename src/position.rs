@@ -0,0 +1,11 @@
+/// Where a business day sits within its month and year, as returned by
+/// [`Calendar::business_day_position`](crate::Calendar::business_day_position).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessDayPosition {
+    /// 1-based index of the business day within its month
+    pub index_in_month: u32,
+    /// 1-based index of the business day within its year
+    pub index_in_year: u32,
+    /// Number of business days left in the month after this one, 0 being the last
+    pub index_from_month_end: u32,
+}
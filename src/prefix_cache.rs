@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{naive::NaiveDate, Datelike};
+
+use crate::Calendar;
+
+/// A shared [`Calendar`] that keeps the running business day count of every year it is asked
+/// about, so counting business days within a year takes constant time after the first query for
+/// that year.
+///
+/// The calendar is behind an [`Arc`] so it cannot change while its counts are cached.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use chrono::NaiveDate;
+/// # use business::{Calendar, PrefixSumCachedCalendar};
+/// let xmas = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+/// let cal = PrefixSumCachedCalendar::new(Arc::new(Calendar::with_holidays(&[xmas])));
+/// let start = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+///
+/// assert_eq!(cal.business_day_count_fast_for_year(start, end), 21);
+/// assert_eq!(cal.cached_years(), 1);
+/// ```
+#[derive(Debug)]
+pub struct PrefixSumCachedCalendar {
+    calendar: Arc<Calendar>,
+    prefix_sums: Mutex<HashMap<i32, [u32; 367]>>,
+}
+
+impl PrefixSumCachedCalendar {
+    /// Wraps the calendar, with nothing cached yet.
+    pub fn new(calendar: Arc<Calendar>) -> Self {
+        Self {
+            calendar,
+            prefix_sums: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the wrapped calendar.
+    pub fn calendar(&self) -> &Arc<Calendar> {
+        &self.calendar
+    }
+
+    /// Counts business days in `[start, end]`, using the cached
+    /// [`business_day_count_prefix_sum`](Calendar::business_day_count_prefix_sum) of the year
+    /// when both dates are in the same one, and counting day by day otherwise. Returns `0` if
+    /// `end` is before `start`.
+    pub fn business_day_count_fast_for_year(&self, start: NaiveDate, end: NaiveDate) -> u32 {
+        if start > end || start.year() != end.year() {
+            return self.calendar.count_business_days_inclusive(start, end);
+        }
+        let mut prefix_sums = self.prefix_sums.lock().unwrap();
+        let prefix = prefix_sums
+            .entry(start.year())
+            .or_insert_with(|| self.calendar.business_day_count_prefix_sum(start.year()));
+        prefix[end.ordinal() as usize] - prefix[start.ordinal0() as usize]
+    }
+
+    /// Returns the number of years with a cached running count.
+    pub fn cached_years(&self) -> usize {
+        self.prefix_sums.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_uncached_count() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()]);
+        let cached = PrefixSumCachedCalendar::new(Arc::new(cal));
+        let dates: Vec<_> = [(2023, 12, 30), (2024, 1, 1), (2024, 2, 29), (2024, 12, 31)]
+            .into_iter()
+            .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap())
+            .collect();
+
+        for &start in &dates {
+            for &end in &dates {
+                assert_eq!(
+                    cached.business_day_count_fast_for_year(start, end),
+                    cached.calendar().count_business_days_inclusive(start, end),
+                    "{} to {}",
+                    start,
+                    end
+                );
+            }
+        }
+        // Ranges across years are counted without caching
+        assert_eq!(cached.cached_years(), 2);
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use chrono::{naive::NaiveDate, Datelike};
+
+use crate::{last_day_of_month, Calendar};
+
+/// A holiday falling on the same date every year, as returned by
+/// [`Calendar::to_recurrence_rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecurringHoliday {
+    /// Month of the holiday, from 1 to 12
+    pub month: u32,
+    /// Day of the month of the holiday
+    pub day: u32,
+}
+
+/// What [`Calendar::add_holiday_recurring_annually`] does in years where the day does not exist
+/// in the month, such as the 29th of February outside leap years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidDayPolicy {
+    /// Adds no holiday that year
+    Skip,
+    /// Adds the last day of the month instead
+    LastDayOfMonth,
+}
+
+impl Calendar {
+    /// Adds a holiday on the same month and day in every year of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not between 1 and 12, or `day` is not between 1 and 31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, InvalidDayPolicy};
+    /// let mut cal = Calendar::workweek();
+    /// cal.add_holiday_recurring_annually(2, 29, 2023..=2024, InvalidDayPolicy::LastDayOfMonth);
+    ///
+    /// assert_eq!(
+    ///     cal.holidays,
+    ///     [
+    ///         NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+    ///     ]
+    ///     .into_iter()
+    ///     .collect()
+    /// );
+    /// ```
+    pub fn add_holiday_recurring_annually(
+        &mut self,
+        month: u32,
+        day: u32,
+        year_range: RangeInclusive<i32>,
+        policy: InvalidDayPolicy,
+    ) {
+        assert!((1..=12).contains(&month), "month must be between 1 and 12");
+        assert!((1..=31).contains(&day), "day must be between 1 and 31");
+        for year in year_range {
+            let date = match (NaiveDate::from_ymd_opt(year, month, day), policy) {
+                (Some(date), _) => date,
+                (None, InvalidDayPolicy::Skip) => continue,
+                (None, InvalidDayPolicy::LastDayOfMonth) => last_day_of_month(year, month),
+            };
+            self.holidays.insert(date);
+        }
+    }
+
+    /// Returns the holidays that fall on the same date in every year from the earliest holiday's
+    /// year to the latest one's, sorted by date. Holidays that move from year to year, or are
+    /// missing in any year, are left out. A calendar whose holidays are all in a single year has
+    /// no recurring holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, RecurringHoliday};
+    /// let mut holidays = Vec::new();
+    /// for year in 2020..=2022 {
+    ///     holidays.push(NaiveDate::from_ymd_opt(year, 12, 25).unwrap());
+    /// }
+    /// // Thanksgiving
+    /// holidays.push(NaiveDate::from_ymd_opt(2020, 11, 26).unwrap());
+    /// holidays.push(NaiveDate::from_ymd_opt(2021, 11, 25).unwrap());
+    /// holidays.push(NaiveDate::from_ymd_opt(2022, 11, 24).unwrap());
+    ///
+    /// let cal = Calendar::with_holidays(&holidays);
+    /// assert_eq!(
+    ///     cal.to_recurrence_rules(),
+    ///     vec![RecurringHoliday { month: 12, day: 25 }]
+    /// );
+    /// ```
+    pub fn to_recurrence_rules(&self) -> Vec<RecurringHoliday> {
+        let years = self.holidays.iter().map(|date| date.year());
+        let (first, last) = match (years.clone().min(), years.max()) {
+            (Some(first), Some(last)) if first < last => (first, last),
+            _ => return Vec::new(),
+        };
+
+        let mut counts: HashMap<RecurringHoliday, i32> = HashMap::new();
+        for date in &self.holidays {
+            let holiday = RecurringHoliday {
+                month: date.month(),
+                day: date.day(),
+            };
+            *counts.entry(holiday).or_default() += 1;
+        }
+
+        let year_count = last - first + 1;
+        let mut rules: Vec<_> = counts
+            .into_iter()
+            .filter(|&(_, count)| count == year_count)
+            .map(|(holiday, _)| holiday)
+            .collect();
+        rules.sort_unstable();
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn holiday_missing_in_a_year_is_not_recurring() {
+        let cal = Calendar::with_holidays(&[
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 12, 25).unwrap(),
+        ]);
+
+        assert_eq!(
+            cal.to_recurrence_rules(),
+            vec![RecurringHoliday { month: 1, day: 1 }]
+        );
+    }
+
+    #[test]
+    fn single_year_has_no_recurring_holidays() {
+        let cal = Calendar::with_holidays(&[NaiveDate::from_ymd_opt(2020, 12, 25).unwrap()]);
+
+        assert!(cal.to_recurrence_rules().is_empty());
+        assert!(Calendar::workweek().to_recurrence_rules().is_empty());
+    }
+
+    #[test]
+    fn recurring_holiday_skips_missing_days() {
+        let mut cal = Calendar::workweek();
+        cal.add_holiday_recurring_annually(2, 29, 2021..=2028, InvalidDayPolicy::Skip);
+        cal.add_holiday_recurring_annually(12, 25, 2021..=2023, InvalidDayPolicy::Skip);
+
+        let mut holidays: Vec<_> = cal.holidays.iter().cloned().collect();
+        holidays.sort_unstable();
+        assert_eq!(
+            holidays,
+            vec![
+                NaiveDate::from_ymd_opt(2021, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+                NaiveDate::from_ymd_opt(2028, 2, 29).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn recurring_holiday_with_invalid_month_panics() {
+        Calendar::workweek().add_holiday_recurring_annually(
+            13,
+            1,
+            2022..=2022,
+            InvalidDayPolicy::Skip,
+        );
+    }
+}
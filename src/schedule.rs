@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use chrono::naive::NaiveDate;
+
+use crate::Calendar;
+
+impl Calendar {
+    /// Checks that a schedule of dates are all business days, in ascending order and without
+    /// duplicates, returning every problem found. An empty result means the schedule is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use business::{Calendar, ScheduleValidationError};
+    /// let cal = Calendar::workweek();
+    /// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+    /// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+    /// let mon = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+    ///
+    /// assert!(cal.validate_schedule(&[fri, mon]).is_empty());
+    /// assert_eq!(
+    ///     cal.validate_schedule(&[mon, sat]),
+    ///     vec![
+    ///         ScheduleValidationError::OutOfOrder(1),
+    ///         ScheduleValidationError::NotABusinessDay(sat),
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate_schedule(&self, dates: &[NaiveDate]) -> Vec<ScheduleValidationError> {
+        let mut errors = Vec::new();
+        let mut seen = HashSet::with_capacity(dates.len());
+        for (i, &date) in dates.iter().enumerate() {
+            if i > 0 && date < dates[i - 1] {
+                errors.push(ScheduleValidationError::OutOfOrder(i));
+            }
+            if !seen.insert(date) {
+                errors.push(ScheduleValidationError::Duplicate(date));
+            }
+            if !self.is_business_day(date) {
+                errors.push(ScheduleValidationError::NotABusinessDay(date));
+            }
+        }
+        errors
+    }
+}
+
+/// Problems found by [`Calendar::validate_schedule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleValidationError {
+    /// The date is not a business day
+    NotABusinessDay(NaiveDate),
+    /// The date at this index is earlier than the one before it
+    OutOfOrder(usize),
+    /// The date appears earlier in the schedule
+    Duplicate(NaiveDate),
+}
+
+impl fmt::Display for ScheduleValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleValidationError::NotABusinessDay(date) => {
+                write!(f, "{} is not a business day", date)
+            }
+            ScheduleValidationError::OutOfOrder(i) => {
+                write!(f, "date at index {} is earlier than the previous date", i)
+            }
+            ScheduleValidationError::Duplicate(date) => {
+                write!(f, "{} appears more than once", date)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_every_problem() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let dates = [23, 25, 24, 28, 28].map(|day| NaiveDate::from_ymd_opt(2020, 12, day).unwrap());
+
+        assert_eq!(
+            cal.validate_schedule(&dates),
+            vec![
+                ScheduleValidationError::NotABusinessDay(xmas),
+                ScheduleValidationError::OutOfOrder(2),
+                ScheduleValidationError::Duplicate(dates[4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let date = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+
+        assert_eq!(
+            ScheduleValidationError::Duplicate(date).to_string(),
+            "2020-12-25 appears more than once"
+        );
+        assert_eq!(
+            ScheduleValidationError::OutOfOrder(3).to_string(),
+            "date at index 3 is earlier than the previous date"
+        );
+    }
+}
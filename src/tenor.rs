@@ -0,0 +1,13 @@
+/// The length of a financial instrument's term, as used by
+/// [`Calendar::maturity_date`](crate::Calendar::maturity_date).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tenor {
+    /// A number of business days
+    Days(u32),
+    /// A number of calendar weeks
+    Weeks(u32),
+    /// A number of calendar months
+    Months(u32),
+    /// A number of calendar years
+    Years(u32),
+}
@@ -0,0 +1,305 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+
+use chrono::naive::NaiveDate;
+
+use crate::Calendar;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Caches for every [`ThreadLocalCachedCalendar`] used on this thread, by id, each with a
+    /// handle that is dead once every clone of its calendar has been dropped.
+    static CACHES: RefCell<HashMap<usize, (Weak<()>, LruCache)>> = RefCell::new(HashMap::new());
+}
+
+/// A shared [`Calendar`] that remembers recent [`is_business_day`](Self::is_business_day)
+/// results in a cache local to each thread, so repeated lookups of the same dates need no
+/// synchronization between threads.
+///
+/// Each thread keeps up to `capacity` dates per calendar, evicting the least recently used one
+/// when full. Clones share their caches, since they wrap the same calendar.
+///
+/// A thread's cache outlives the calendar it belongs to: once every clone has been dropped, the
+/// cache is only freed when the thread exits or next starts caching another calendar. Call
+/// [`clear_thread_cache`](Self::clear_thread_cache) to free it sooner.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use chrono::NaiveDate;
+/// # use business::{Calendar, ThreadLocalCachedCalendar};
+/// let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+/// let cal = ThreadLocalCachedCalendar::new(Arc::new(Calendar::with_holidays(&[xmas])), 1024);
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let cal = cal.clone();
+///         thread::spawn(move || cal.is_business_day(xmas))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), false);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThreadLocalCachedCalendar {
+    calendar: Arc<Calendar>,
+    id: usize,
+    alive: Arc<()>,
+    capacity: usize,
+}
+
+impl ThreadLocalCachedCalendar {
+    /// Wraps the calendar, caching up to `capacity` dates per thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn new(calendar: Arc<Calendar>, capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than 0");
+        Self {
+            calendar,
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            alive: Arc::new(()),
+            capacity,
+        }
+    }
+
+    /// Returns the wrapped calendar.
+    pub fn calendar(&self) -> &Arc<Calendar> {
+        &self.calendar
+    }
+
+    /// Returns `true` if the date is a business day, looking it up in this thread's cache first.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        CACHES.with(|caches| {
+            let mut caches = caches.borrow_mut();
+            if !caches.contains_key(&self.id) {
+                caches.retain(|_, (alive, _)| alive.strong_count() > 0);
+                caches.insert(
+                    self.id,
+                    (Arc::downgrade(&self.alive), LruCache::new(self.capacity)),
+                );
+            }
+            let (_, cache) = caches.get_mut(&self.id).expect("cache was just inserted");
+            match cache.get(date) {
+                Some(is_business) => is_business,
+                None => {
+                    let is_business = self.calendar.is_business_day(date);
+                    cache.insert(date, is_business);
+                    is_business
+                }
+            }
+        })
+    }
+
+    /// Returns the number of dates cached for this calendar on the current thread.
+    pub fn cached_len(&self) -> usize {
+        CACHES.with(|caches| {
+            caches
+                .borrow()
+                .get(&self.id)
+                .map_or(0, |(_, cache)| cache.len())
+        })
+    }
+
+    /// Frees this calendar's cache on the current thread. Caches on other threads are kept.
+    pub fn clear_thread_cache(&self) {
+        CACHES.with(|caches| {
+            caches.borrow_mut().remove(&self.id);
+        })
+    }
+}
+
+/// Least recently used cache of business day lookups. Entries are linked from most to least
+/// recently used by their index in `nodes`, so lookups, inserts and evictions take constant time.
+#[derive(Debug)]
+struct LruCache {
+    capacity: usize,
+    index: HashMap<NaiveDate, usize>,
+    nodes: Vec<Node>,
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+#[derive(Debug)]
+struct Node {
+    date: NaiveDate,
+    is_business: bool,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            index: HashMap::with_capacity(capacity),
+            nodes: Vec::with_capacity(capacity),
+            head: None,
+            tail: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    fn get(&mut self, date: NaiveDate) -> Option<bool> {
+        let node = *self.index.get(&date)?;
+        self.move_to_front(node);
+        Some(self.nodes[node].is_business)
+    }
+
+    fn insert(&mut self, date: NaiveDate, is_business: bool) {
+        if let Some(&node) = self.index.get(&date) {
+            self.nodes[node].is_business = is_business;
+            self.move_to_front(node);
+            return;
+        }
+
+        let node = if self.nodes.len() < self.capacity {
+            self.nodes.push(Node {
+                date,
+                is_business,
+                prev: None,
+                next: None,
+            });
+            self.nodes.len() - 1
+        } else {
+            let oldest = self
+                .tail
+                .expect("a full cache has a least recently used entry");
+            self.unlink(oldest);
+            self.index.remove(&self.nodes[oldest].date);
+            self.nodes[oldest].date = date;
+            self.nodes[oldest].is_business = is_business;
+            oldest
+        };
+        self.index.insert(date, node);
+        self.push_front(node);
+    }
+
+    fn move_to_front(&mut self, node: usize) {
+        if self.head != Some(node) {
+            self.unlink(node);
+            self.push_front(node);
+        }
+    }
+
+    fn unlink(&mut self, node: usize) {
+        let Node { prev, next, .. } = self.nodes[node];
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, node: usize) {
+        self.nodes[node].prev = None;
+        self.nodes[node].next = self.head;
+        match self.head {
+            Some(head) => self.nodes[head].prev = Some(node),
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let days = [1, 2, 3].map(|day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap());
+        let mut cache = LruCache::new(2);
+
+        cache.insert(days[0], false);
+        cache.insert(days[1], false);
+        assert_eq!(cache.get(days[0]), Some(false));
+        cache.insert(days[2], true);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(days[1]), None);
+        assert_eq!(cache.get(days[0]), Some(false));
+        assert_eq!(cache.get(days[2]), Some(true));
+    }
+
+    #[test]
+    fn caches_per_calendar_and_thread() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = ThreadLocalCachedCalendar::new(Arc::new(Calendar::with_holidays(&[xmas])), 8);
+        let other = ThreadLocalCachedCalendar::new(Arc::new(Calendar::workweek()), 8);
+
+        assert!(!cal.is_business_day(xmas));
+        assert!(other.is_business_day(xmas));
+        assert_eq!(cal.cached_len(), 1);
+
+        let clone = cal.clone();
+        let len_on_other_thread = std::thread::spawn(move || clone.cached_len())
+            .join()
+            .unwrap();
+        assert_eq!(len_on_other_thread, 0);
+    }
+
+    #[test]
+    fn evicts_in_order_of_use_when_full() {
+        let days: Vec<_> = (1..=5)
+            .map(|day| NaiveDate::from_ymd_opt(2022, 10, day).unwrap())
+            .collect();
+        let mut cache = LruCache::new(3);
+
+        for &day in &days[..3] {
+            cache.insert(day, true);
+        }
+        assert_eq!(cache.get(days[0]), Some(true));
+        cache.insert(days[1], false);
+        cache.insert(days[3], true);
+        cache.insert(days[4], true);
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(days[2]), None);
+        assert_eq!(cache.get(days[0]), None);
+        assert_eq!(cache.get(days[1]), Some(false));
+        assert_eq!(cache.get(days[3]), Some(true));
+        assert_eq!(cache.get(days[4]), Some(true));
+    }
+
+    #[test]
+    fn frees_caches_of_dropped_calendars() {
+        let date = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = ThreadLocalCachedCalendar::new(Arc::new(Calendar::workweek()), 8);
+        let id = cal.id;
+        cal.is_business_day(date);
+        drop(cal);
+
+        let other = ThreadLocalCachedCalendar::new(Arc::new(Calendar::workweek()), 8);
+        other.is_business_day(date);
+
+        assert!(CACHES.with(|caches| !caches.borrow().contains_key(&id)));
+    }
+
+    #[test]
+    fn clear_thread_cache_frees_cache() {
+        let date = NaiveDate::from_ymd_opt(2022, 10, 3).unwrap();
+        let cal = ThreadLocalCachedCalendar::new(Arc::new(Calendar::workweek()), 8);
+        cal.is_business_day(date);
+
+        cal.clear_thread_cache();
+
+        assert_eq!(cal.cached_len(), 0);
+        assert!(cal.is_business_day(date));
+        assert_eq!(cal.cached_len(), 1);
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+
+use chrono::{naive::NaiveDate, Duration};
+
+use crate::Calendar;
+
+/// A series of values, one per business day, as created by
+/// [`Calendar::business_day_time_series`].
+///
+/// Values are indexed by business day number, starting from `0` for the first business day.
+/// Lookups by date can fill non-business days from the nearest business day before or after.
+///
+/// ```
+/// # use chrono::NaiveDate;
+/// # use business::Calendar;
+/// let cal = Calendar::workweek();
+/// let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+/// let sat = NaiveDate::from_ymd_opt(2022, 10, 1).unwrap();
+/// let wed = NaiveDate::from_ymd_opt(2022, 10, 5).unwrap();
+///
+/// let series = cal.business_day_time_series(fri, wed, &[1.0, 2.0, 3.0]).unwrap();
+///
+/// assert_eq!(series.get(1), Some(2.0));
+/// assert_eq!(series.forward_fill(sat), Some(1.0));
+/// assert_eq!(series.backward_fill(sat), Some(2.0));
+/// assert_eq!(series.rolling_mean(2), vec![1.5, 2.5]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessDayTimeSeries {
+    dates: Vec<NaiveDate>,
+    values: Vec<f64>,
+}
+
+impl BusinessDayTimeSeries {
+    /// The business days of the series, in chronological order.
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    /// The values of the series, one per business day.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// The number of business days in the series.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the series has no business days.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value for the given business day number, starting from `0`.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        self.values.get(index).copied()
+    }
+
+    /// Returns the value on the given date, or `None` if it is not a business day in the series.
+    pub fn value_on(&self, date: NaiveDate) -> Option<f64> {
+        self.dates
+            .binary_search(&date)
+            .ok()
+            .map(|index| self.values[index])
+    }
+
+    /// Returns the value on the latest business day on or before the given date, or `None` if
+    /// the date is before the series starts.
+    pub fn forward_fill(&self, date: NaiveDate) -> Option<f64> {
+        match self.dates.binary_search(&date) {
+            Ok(index) => Some(self.values[index]),
+            Err(0) => None,
+            Err(index) => Some(self.values[index - 1]),
+        }
+    }
+
+    /// Returns the value on the earliest business day on or after the given date, or `None` if
+    /// the date is after the series ends.
+    pub fn backward_fill(&self, date: NaiveDate) -> Option<f64> {
+        let index = self
+            .dates
+            .binary_search(&date)
+            .unwrap_or_else(|index| index);
+        self.get(index)
+    }
+
+    /// Returns the mean of every `window` consecutive business days, one for each business day
+    /// from the `window`th onwards. Returns an empty list if the series is shorter than `window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is `0`.
+    pub fn rolling_mean(&self, window: usize) -> Vec<f64> {
+        assert!(window > 0, "window must be greater than 0");
+        self.values
+            .windows(window)
+            .map(|values| values.iter().sum::<f64>() / window as f64)
+            .collect()
+    }
+}
+
+/// Errors returned by [`Calendar::business_day_time_series`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TsError {
+    /// The number of values does not match the number of business days
+    LengthMismatch {
+        /// Number of business days in the range
+        expected: usize,
+        /// Number of values given
+        found: usize,
+    },
+}
+
+impl fmt::Display for TsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TsError::LengthMismatch { expected, found } => write!(
+                f,
+                "expected {} values, one per business day, but found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TsError {}
+
+impl Calendar {
+    /// Pairs each business day in `[start, end)` with a value, in chronological order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TsError::LengthMismatch`] unless there is exactly one value per business day, as
+    /// counted by [`Calendar::business_days_between`].
+    pub fn business_day_time_series(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        values: &[f64],
+    ) -> Result<BusinessDayTimeSeries, TsError> {
+        let mut dates = Vec::with_capacity(values.len());
+        let mut date = start;
+        while date < end {
+            if self.is_business_day(date) {
+                dates.push(date);
+            }
+            date += Duration::days(1);
+        }
+
+        if dates.len() != values.len() {
+            return Err(TsError::LengthMismatch {
+                expected: dates.len(),
+                found: values.len(),
+            });
+        }
+
+        Ok(BusinessDayTimeSeries {
+            dates,
+            values: values.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_must_match_business_days() {
+        let cal = Calendar::workweek();
+        let fri = NaiveDate::from_ymd_opt(2022, 9, 30).unwrap();
+        let tue = NaiveDate::from_ymd_opt(2022, 10, 4).unwrap();
+
+        let err = cal.business_day_time_series(fri, tue, &[1.0]).unwrap_err();
+
+        assert_eq!(
+            err,
+            TsError::LengthMismatch {
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected 2 values, one per business day, but found 1"
+        );
+    }
+
+    #[test]
+    fn fills_around_holiday_and_range_ends() {
+        let xmas = NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        let cal = Calendar::with_holidays(&[xmas]);
+        let start = NaiveDate::from_ymd_opt(2020, 12, 23).unwrap();
+        let end = NaiveDate::from_ymd_opt(2020, 12, 29).unwrap();
+
+        let series = cal
+            .business_day_time_series(start, end, &[1.0, 2.0, 3.0])
+            .unwrap();
+
+        assert_eq!(series.value_on(xmas), None);
+        assert_eq!(series.forward_fill(xmas), Some(2.0));
+        assert_eq!(series.backward_fill(xmas), Some(3.0));
+        assert_eq!(series.forward_fill(start - Duration::days(1)), None);
+        assert_eq!(series.backward_fill(end), None);
+        assert!(series.rolling_mean(4).is_empty());
+    }
+}